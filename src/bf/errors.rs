@@ -6,10 +6,38 @@ pub type Result<T> = std::result::Result<T, BFError>;
 pub enum BFError {
     #[error("args_amount is zero (should be greater)")]
     NoArgs,
+    #[error("args_amount ({args_amount}) is too big (max: {max})")]
+    TooManyArgs { args_amount: usize, max: usize },
     #[error("string `{0}` contains invalid characters (got expected '0' or '1')")]
     InvalidString(String),
     #[error("string length should be power of two (got: `{0}`)")]
     NotPowTwo(usize),
     #[error("given argument ({given}) is out of bounds ({bounds})")]
     ArgOutOfBounds { given: usize, bounds: usize },
+    #[error("given variable ({given}) is out of bounds ({bounds})")]
+    VarOutOfBounds { given: usize, bounds: usize },
+    #[error("value of variable x{0} is not given")]
+    MissingVar(usize),
+    #[error("given value ({0}) is not a bit")]
+    InvalidBit(u8),
+    #[error("given invalid degree: {0}")]
+    InvalidDeg(usize),
+    #[error("dimension mismatch (expected: {expected}, got: {given})")]
+    DimMismatch { expected: usize, given: usize },
+    #[error("given order is not a permutation of arguments (got: `{0:?}`)")]
+    InvalidPermutation(Vec<usize>),
+    #[error("given values ({values:#b}) have bits outside of mask ({mask:#b})")]
+    ValuesOutsideMask { mask: usize, values: usize },
+    #[error("string `{0}` is not a valid monomial of algebraic normal form")]
+    InvalidAnf(String),
+    #[error("variable {0} is given more than once")]
+    DuplicateVar(usize),
+    #[error("string length is invalid (expected: {expected}, got: {given})")]
+    InvalidLength { given: usize, expected: usize },
+    #[error("bits outside of truth table are set")]
+    UnusedBitsSet,
+    #[error("word size mismatch (expected: {expected} bits, got: {given} bits)")]
+    WordSizeMismatch { expected: usize, given: usize },
+    #[error("function equals 0 for all arguments")]
+    ZeroFunction,
 }
@@ -0,0 +1,142 @@
+use super::errors::{BFError, Result};
+use super::utils::pow2;
+use super::BF;
+use std::ops::Add;
+
+/// IntBF represents integer valued function of boolean arguments.
+/// Used to build weighted combinations of boolean functions.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntBF {
+    /// Function values for arguments `0, 1, ..., 2^args_amount - 1`.
+    pub values: Vec<i64>,
+
+    /// Amount of arguments function takes.
+    pub args_amount: usize,
+}
+
+impl IntBF {
+    /// Creates function, which equals `-1` where `bf` equals `0`
+    /// and `1` where `bf` equals `1`.
+    pub fn signed(bf: &BF) -> Self {
+        IntBF {
+            values: bf.iter().map(|value| if value { 1 } else { -1 }).collect(),
+            args_amount: bf.args_amount,
+        }
+    }
+
+    /// Returns pointwise sum of functions.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn try_add(&self, other: &IntBF) -> Result<Self> {
+        if self.args_amount != other.args_amount {
+            return Err(BFError::DimMismatch {
+                expected: self.args_amount,
+                given: other.args_amount,
+            });
+        }
+
+        let values = self
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(a, b)| a + b)
+            .collect();
+
+        Ok(IntBF {
+            values,
+            args_amount: self.args_amount,
+        })
+    }
+
+    /// Returns boolean function, which equals `1` where value is greater than `t`.
+    pub fn threshold(&self, t: i64) -> BF {
+        let mut bf = BF::zero(self.args_amount).expect("args_amount is not zero");
+        for args in (0..pow2(self.args_amount)).filter(|&args| self.values[args] > t) {
+            bf.set(args).expect("args is in bounds");
+        }
+
+        bf
+    }
+}
+
+impl From<&BF> for IntBF {
+    /// Creates function, which equals `bf` as integer `0/1` vector.
+    fn from(bf: &BF) -> Self {
+        IntBF {
+            values: bf.iter().map(i64::from).collect(),
+            args_amount: bf.args_amount,
+        }
+    }
+}
+
+impl Add for IntBF {
+    type Output = IntBF;
+
+    /// Returns pointwise sum of functions.
+    ///
+    /// # Panics
+    /// Panics if functions have different amount of arguments.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.try_add(&rhs)
+            .expect("functions have the same amount of arguments")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_works() {
+        let bf = BF::from_str("0110").unwrap();
+        assert_eq!(IntBF::from(&bf).values, vec![0, 1, 1, 0]);
+        assert_eq!(IntBF::signed(&bf).values, vec![-1, 1, 1, -1]);
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(IntBF::from(&bf).threshold(0), bf);
+            assert_eq!(IntBF::signed(&bf).threshold(0), bf);
+        }
+    }
+
+    #[test]
+    fn add_works() {
+        let f = BF::from_str("01010101").unwrap();
+        let g = BF::from_str("00110011").unwrap();
+        let h = BF::from_str("00001111").unwrap();
+
+        // sum of 0/1 representations counts ones
+        let sum = IntBF::from(&f) + IntBF::from(&g) + IntBF::from(&h);
+        assert_eq!(sum.values, vec![0, 1, 1, 2, 1, 2, 2, 3]);
+
+        // majority vote of three functions
+        let sum = IntBF::signed(&f) + IntBF::signed(&g) + IntBF::signed(&h);
+        assert_eq!(sum.threshold(0).to_string(), "00010111");
+
+        for n in 1..=6 {
+            let funcs: Vec<BF> = (0..3).map(|_| BF::random(n).unwrap()).collect();
+            let sum = funcs
+                .iter()
+                .map(IntBF::signed)
+                .reduce(|acc, g| acc + g)
+                .unwrap();
+
+            let (f, g, h) = (&funcs[0], &funcs[1], &funcs[2]);
+            let majority =
+                (f.clone() & g.clone()) | (f.clone() & h.clone()) | (g.clone() & h.clone());
+            assert_eq!(sum.threshold(0), majority);
+        }
+
+        let f = IntBF::from(&BF::zero(2).unwrap());
+        let g = IntBF::from(&BF::zero(3).unwrap());
+        assert_eq!(
+            f.try_add(&g),
+            Err(BFError::DimMismatch {
+                expected: 2,
+                given: 3
+            })
+        );
+    }
+}
@@ -1,21 +1,58 @@
 #![allow(clippy::unnecessary_cast)]
 
 pub mod errors;
+pub mod intbf;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod utils;
 
 use errors::{BFError, Result};
+use std::collections::HashMap;
 use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
 use utils::*;
 
 use itertools::Itertools;
 use rand::{distributions::Uniform, Rng};
 use std::str::FromStr;
 
+use crate::bm::BM;
 use crate::Value;
 
+/// Maximal amount of arguments a function can be created with.
+///
+/// Truth table of 32 arguments already takes 512 MiB. Cap is lowered on targets
+/// where `2^args_amount` doesn't fit into `usize`.
+pub const MAX_ARGS: usize = if usize::BITS > 32 {
+    32
+} else {
+    usize::BITS as usize - 1
+};
+
+/// Checks that function with `args_amount` arguments can be created.
+fn check_args_amount(args_amount: usize) -> Result<()> {
+    if args_amount == 0 {
+        return Err(BFError::NoArgs);
+    }
+
+    if args_amount > MAX_ARGS {
+        return Err(BFError::TooManyArgs {
+            args_amount,
+            max: MAX_ARGS,
+        });
+    }
+
+    Ok(())
+}
+
 /// BF represents boolean function.
 /// Arguments are stored in little-endian fashion.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "serialization::BFRepr", into = "serialization::BFRepr")
+)]
 pub struct BF {
     /// Vector, holding function values for corresponding arguments.
     ///
@@ -32,11 +69,10 @@ impl BF {
     /// Creates boolean function which equals `0` for all arguments.
     ///
     /// # Errors
-    /// Returns `BFError::NoArgs` if args_amount == 0
+    /// Returns `BFError::NoArgs` if args_amount == 0,
+    /// `BFError::TooManyArgs` if args_amount > MAX_ARGS
     pub fn zero(args_amount: usize) -> Result<Self> {
-        if args_amount == 0 {
-            return Err(BFError::NoArgs);
-        }
+        check_args_amount(args_amount)?;
 
         let cap = div_ws_ceil(pow2(args_amount));
         Ok(BF {
@@ -48,11 +84,10 @@ impl BF {
     /// Creates boolean function which equals `1` for all arguments.
     ///
     /// # Errors
-    /// Returns `BFError::NoArgs` if args_amount == 0
+    /// Returns `BFError::NoArgs` if args_amount == 0,
+    /// `BFError::TooManyArgs` if args_amount > MAX_ARGS
     pub fn one(args_amount: usize) -> Result<Self> {
-        if args_amount == 0 {
-            return Err(BFError::NoArgs);
-        }
+        check_args_amount(args_amount)?;
 
         let cap = div_ws_ceil(pow2(args_amount));
         let bits_in_last_factor = mod_ws(pow2(args_amount));
@@ -73,11 +108,10 @@ impl BF {
     /// Result is uniformly distributed.
     ///
     /// # Errors
-    /// Returns `BFError::NoArgs` if args_amount == 0
+    /// Returns `BFError::NoArgs` if args_amount == 0,
+    /// `BFError::TooManyArgs` if args_amount > MAX_ARGS
     pub fn random(args_amount: usize) -> Result<Self> {
-        if args_amount == 0 {
-            return Err(BFError::NoArgs);
-        }
+        check_args_amount(args_amount)?;
 
         let cap = div_ws_ceil(pow2(args_amount));
         let bits_in_last_factor = mod_ws(pow2(args_amount));
@@ -103,10 +137,23 @@ impl BF {
 
         self.values
             .iter()
-            .fold(0, |acc, &factor| acc + weight(factor as usize))
+            .fold(0, |acc, &factor| acc + factor.count_ones() as usize)
+    }
+
+    /// Returns `Some(value)` if function equals `value` for all arguments,
+    /// otherwise returns `None`.
+    pub fn is_constant(&self) -> Option<u8> {
+        match self.weight() {
+            0 => Some(0),
+            w if w == pow2(self.args_amount) => Some(1),
+            _ => None,
+        }
     }
 
     /// Calculates Mobuis transform inplace.
+    ///
+    /// Transform is an involution, so applying it twice restores a function.
+    /// Works for every supported arity, i.e. up to `MAX_ARGS` arguments.
     pub fn mobius(&mut self) -> &mut Self {
         let m = log2(WORD_BIT_SIZE);
         for value in self.values.iter_mut() {
@@ -182,18 +229,18 @@ impl BF {
         let mut bf_copy = self.clone();
         let bf_mob = bf_copy.mobius();
 
-        if bf_mob.weight() == 0 {
+        if bf_mob.is_constant() == Some(0) {
             return String::from("0");
         }
 
-        let mut anf: String = (1..pow2(bf_mob.args_amount) as u128)
-            .into_iter()
-            .filter(|&args| bf_mob.eval(args as usize) == 1)
-            .map(|args| {
-                (0..WORD_BIT_SIZE)
+        let mut anf: String = bf_mob
+            .iter()
+            .positions(|coef| coef)
+            .filter(|&mask| mask != 0)
+            .map(|mask| {
+                self.monomial_vars(mask)
                     .into_iter()
-                    .filter(|&i| (args >> i) & 1 == 1)
-                    .map(|i| format!("x{}", bf_mob.args_amount - i))
+                    .map(|var| format!("x{var}"))
                     .intersperse(String::from("&"))
                     .collect::<String>()
             })
@@ -236,35 +283,18 @@ impl BF {
         deg
     }
 
-    // Get walsh adamar coefficients
-    pub fn walsh_adamar(&self) -> Vec<i32> {
+    // Get walsh adamar coefficients.
+    // Coefficients reach ±2^n, so they are stored in i64.
+    pub fn walsh_adamar(&self) -> Vec<i64> {
         let mut char_vec = (0..pow2(self.args_amount))
-            .into_iter()
             .map(|arg| match self.eval(arg) {
                 0 => 1,
                 1 => -1,
                 _ => panic!("function evaluated to non binary"),
             })
-            .collect::<Vec<i32>>();
+            .collect::<Vec<i64>>();
 
-        for i in 0..self.args_amount {
-            let cs = pow2(i);
-            for j in 0..char_vec.len() / cs {
-                if j & 1 == 0 {
-                    // is even
-                    for k in 0..cs {
-                        char_vec[j * cs + k] += char_vec[(j + 1) * cs + k]; // a + b
-                    }
-                } else {
-                    // is odd
-                    for k in 0..cs {
-                        char_vec[j * cs + k] =
-                            char_vec[(j - 1) * cs + k] - 2 * char_vec[j * cs + k];
-                        // a + b - 2b = a - b
-                    }
-                }
-            }
-        }
+        fast_hadamard_transform(&mut char_vec);
 
         char_vec
     }
@@ -284,9 +314,20 @@ impl BF {
         self.args_amount
     }
 
-    pub fn linear(args_amount: usize, coefs: usize) -> Result<Self> {
-        if args_amount == 0 {
-            return Err(BFError::NoArgs);
+    /// Creates linear function `l(x) = <mask, x>`: xor of arguments selected by `mask`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if args_amount == 0,
+    /// `BFError::TooManyArgs` if args_amount > MAX_ARGS,
+    /// `BFError::ArgOutOfBounds` if `mask >= 2^args_amount`.
+    pub fn linear(args_amount: usize, mask: usize) -> Result<Self> {
+        check_args_amount(args_amount)?;
+
+        if mask >= pow2(args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: mask,
+                bounds: pow2(args_amount),
+            });
         }
 
         let cap = div_ws_ceil(pow2(args_amount));
@@ -304,7 +345,7 @@ impl BF {
         };
 
         for i in 0..pow2(args_amount) {
-            let result = weight(i & coefs) & 1;
+            let result = weight(i & mask) & 1;
 
             if result == 1 {
                 bf.set(i).unwrap();
@@ -314,6 +355,46 @@ impl BF {
         Ok(bf)
     }
 
+    /// Creates affine function `l(x) ^ constant`, where `l` is linear function given by `mask`.
+    ///
+    /// # Errors
+    /// Same as `linear`, and `BFError::InvalidBit` if `constant > 1`.
+    pub fn affine(args_amount: usize, mask: usize, constant: u8) -> Result<Self> {
+        if constant > 1 {
+            return Err(BFError::InvalidBit(constant));
+        }
+
+        let bf = BF::linear(args_amount, mask)?;
+        match constant {
+            0 => Ok(bf),
+            _ => Ok(bf.inverse()),
+        }
+    }
+
+    /// Creates threshold function, which equals `1` exactly on arguments of weight `>= t`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if args_amount == 0,
+    /// `BFError::TooManyArgs` if args_amount > MAX_ARGS
+    pub fn threshold(args_amount: usize, t: usize) -> Result<Self> {
+        let mut bf = BF::zero(args_amount)?;
+        for args in 0..pow2(args_amount) {
+            if weight(args) >= t {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Creates majority function: threshold function with `t = ceil(n/2)`.
+    ///
+    /// # Errors
+    /// Same as `threshold`.
+    pub fn majority(args_amount: usize) -> Result<Self> {
+        BF::threshold(args_amount, args_amount.div_ceil(2))
+    }
+
     // Returns inverse of a funtion
     pub fn inverse(&self) -> Self {
         let args_amount = self.args_amount;
@@ -335,7 +416,8 @@ impl BF {
     pub fn nonlinearity(&self) -> usize {
         let wac = self.walsh_adamar();
 
-        pow2(self.args_amount - 1) - (wac.iter().map(|coef| coef.abs()).max().unwrap() as usize) / 2
+        let max_abs = wac.iter().map(|coef| coef.unsigned_abs()).max().unwrap() as usize;
+        pow2(self.args_amount - 1) - max_abs / 2
     }
 
     // Calculates complete nonlinearity of a function (distance to class of linear structure functions).
@@ -365,35 +447,16 @@ impl BF {
         approx
     }
 
-    // Calculates autocorrelation of a function.
-    pub fn autocor(&self) -> Vec<i32> {
-        let mut autocor_vec = self.walsh_adamar();
-
-        autocor_vec.iter_mut().for_each(|v| *v *= *v);
-
-        for i in 0..self.args_amount {
-            let cs = pow2(i);
-            for j in 0..autocor_vec.len() / cs {
-                if j & 1 == 0 {
-                    // is even
-                    for k in 0..cs {
-                        autocor_vec[j * cs + k] += autocor_vec[(j + 1) * cs + k];
-                        // a + b
-                    }
-                } else {
-                    // is odd
-                    for k in 0..cs {
-                        autocor_vec[j * cs + k] =
-                            autocor_vec[(j - 1) * cs + k] - 2 * autocor_vec[j * cs + k];
-                        // a + b - 2b = a - b
-                    }
-                }
-            }
-        }
-
-        autocor_vec.iter_mut().for_each(|v| *v >>= self.args_amount);
-
-        autocor_vec
+    /// Calculates autocorrelation spectrum of a function:
+    /// `C(a) = sum (-1)^(f(x) ^ f(x ^ a))` for all shifts `a`.
+    ///
+    /// Spectrum is computed as inverse walsh adamar transform of squared walsh adamar
+    /// coefficients, so it takes `O(n * 2^n)` instead of `O(2^(2n))`.
+    /// `C(0)` always equals `2^n`, which doesn't fit into `i32` for `n >= 31`,
+    /// so coefficients are stored in `i64`.
+    pub fn autocor(&self) -> Vec<i64> {
+        let wac = self.walsh_adamar();
+        inverse_walsh_of_product(&wac, &wac, self.args_amount)
     }
 
     // Calcualtes propogation criteria degree.
@@ -410,346 +473,4797 @@ impl BF {
 
         self.args_amount
     }
-}
-
-impl FromStr for BF {
-    type Err = BFError;
 
-    /// Converts string to boolean function
-    /// First char in string correspond to arguments with values zero.
-    /// Last char in string to arguments with values one.
+    /// Calculates number of nodes (including terminals) in the reduced ordered BDD
+    /// of a function, where `order[0]` is the argument tested at the root.
     ///
     /// # Errors
-    /// Returns `BFError::InvalidString` if `s` doesn't consist of zeros and ones,
-    /// or `BFError::NotPowTwo` if `len(s)` is not a power of 2.
-    fn from_str(s: &str) -> Result<Self> {
-        let len = s.len();
-        if len == 1 || !is_pow2(len) {
-            return Err(BFError::NotPowTwo(len));
+    /// Returns `BFError::InvalidPermutation` if `order` is not a permutation of `0..args_amount`.
+    pub fn obdd_size(&self, order: &[usize]) -> Result<usize> {
+        if !is_permutation(order, self.args_amount) {
+            return Err(BFError::InvalidPermutation(order.to_vec()));
         }
 
-        let mut bf = BF::zero(log2(len)).expect("length not zero");
+        // Reorder truth table so that `order[0]` is the most significant bit.
+        // Then cofactors on level k are consecutive chunks of length 2^(n - k).
+        let n = self.args_amount;
+        let table: Vec<u8> = (0..pow2(n))
+            .map(|y| {
+                let args = (0..n)
+                    .filter(|&k| (y >> (n - 1 - k)) & 1 == 1)
+                    .fold(0, |acc, k| acc | pow2(order[k]));
+                self.eval(args)
+            })
+            .collect();
 
-        for (i, bit) in s.chars().enumerate() {
-            match bit {
-                '0' => (), // value already zero
-                '1' => bf.set(i)?,
-                _ => return Err(BFError::InvalidString(s.to_string())),
-            };
+        let mut size = 0;
+        for k in 0..n {
+            let chunk_len = pow2(n - k);
+            let half = chunk_len / 2;
+            size += table
+                .chunks(chunk_len)
+                .filter(|chunk| chunk[..half] != chunk[half..])
+                .unique()
+                .count();
         }
 
-        Ok(bf)
+        // terminal nodes
+        size += table.iter().unique().count();
+
+        Ok(size)
     }
-}
 
-impl fmt::Display for BF {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let string: String = (0..pow2(self.args_amount))
-            .map(|arg| self.eval(arg).to_string())
-            .collect();
+    /// Searches for variable order minimizing OBDD size of a function.
+    /// Returns found order and corresponding OBDD size.
+    ///
+    /// All orders are checked if `args_amount <= 6`, otherwise order is built greedily:
+    /// on each level the argument giving the smallest OBDD is picked,
+    /// leaving the rest of the arguments in ascending order.
+    pub fn best_obdd_order(&self) -> (Vec<usize>, usize) {
+        let n = self.args_amount;
+
+        if n <= 6 {
+            return (0..n)
+                .permutations(n)
+                .map(|order| {
+                    let size = self.obdd_size(&order).expect("order is a permutation");
+                    (order, size)
+                })
+                .min_by_key(|(_, size)| *size)
+                .expect("there is at least one order");
+        }
 
-        write!(f, "{}", string)
+        let mut order = Vec::with_capacity(n);
+        let mut rest: Vec<usize> = (0..n).collect();
+        let mut best_size = 0;
+        while !rest.is_empty() {
+            let (pos, size) = (0..rest.len())
+                .map(|pos| {
+                    let mut candidate = order.clone();
+                    candidate.push(rest[pos]);
+                    candidate.extend(
+                        rest.iter()
+                            .enumerate()
+                            .filter(|&(i, _)| i != pos)
+                            .map(|(_, &v)| v),
+                    );
+                    let size = self.obdd_size(&candidate).expect("order is a permutation");
+                    (pos, size)
+                })
+                .min_by_key(|(_, size)| *size)
+                .expect("rest is not empty");
+
+            order.push(rest.remove(pos));
+            best_size = size;
+        }
+
+        (order, best_size)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns function `g`, such that `g(w) = 0` where walsh adamar coefficient `W_f(w) >= 0`
+    /// and `g(w) = 1` where `W_f(w) < 0`.
+    ///
+    /// For bent function `g` is its dual.
+    pub fn walsh_sign_function(&self) -> Self {
+        let wac = self.walsh_adamar();
 
-    #[test]
-    fn zero_works() {
-        let args_amount = 4;
-        let bf = BF::zero(args_amount).expect("args_amount is not zero");
-        for value in &bf.values {
-            assert!(*value == 0);
+        let mut sign = BF::zero(self.args_amount).expect("args_amount is not zero");
+        for (w, coef) in wac.iter().enumerate() {
+            if *coef < 0 {
+                sign.set(w).expect("w is in bounds");
+            }
         }
 
-        // expected_length holds ceil((2^args_amount)/WORD_BIT_SIZE)
-        let expected_length = (pow2(args_amount) + WORD_BIT_SIZE - 1) / WORD_BIT_SIZE;
-        assert!(bf.values.len() == expected_length);
-        assert!(bf.args_amount == args_amount);
+        sign
     }
 
-    #[test]
-    fn one_works() {
-        let args_amount = WORD_SIZE;
-        let bf = BF::one(args_amount).expect("args_amount is not zero");
+    /// Calculates numerical normal form of a function.
+    ///
+    /// Returns integer coefficients `c[u]`, such that `f(x) = sum c[u] * x^u`
+    /// over integers, where `x^u` is monomial of arguments set in `u`.
+    pub fn nnf(&self) -> Vec<i64> {
+        let mut coefs: Vec<i64> = (0..pow2(self.args_amount))
+            .map(|arg| self.eval(arg) as i64)
+            .collect();
 
-        for value in &bf.values[..bf.values.len() - 1] {
-            assert!(*value == Value::MAX);
+        for i in 0..self.args_amount {
+            let bit = pow2(i);
+            for u in 0..coefs.len() {
+                if u & bit != 0 {
+                    coefs[u] -= coefs[u ^ bit];
+                }
+            }
         }
 
-        // expected_length holds ceil((2^args_amount)/WORD_BIT_SIZE)
-        let expected_length = (pow2(args_amount) + WORD_BIT_SIZE - 1) / WORD_BIT_SIZE;
-        assert!(bf.values.len() == expected_length);
-        assert!(bf.args_amount == args_amount);
-
-        assert!(bf.weight() == pow2(args_amount))
+        coefs
     }
 
-    #[test]
-    fn weight_works() {
-        let args_amount = 2;
-        let bf = BF::one(args_amount).expect("args_amount is not zero");
-        assert!(bf.weight() == 4);
-
-        let args_amount = log2(WORD_BIT_SIZE);
-        let bf = BF::one(args_amount).expect("args_amount is not zero");
-        assert!(bf.weight() == WORD_BIT_SIZE);
+    /// Calculates numerical degree of a function, i.e.
+    /// maximal weight of a monomial with nonzero coefficient in numerical normal form.
+    ///
+    /// Numerical degree is never less than algebraic degree.
+    pub fn numerical_degree(&self) -> usize {
+        self.nnf()
+            .iter()
+            .enumerate()
+            .filter(|(_, &coef)| coef != 0)
+            .map(|(u, _)| weight(u))
+            .max()
+            .unwrap_or(0)
     }
 
-    #[test]
-    fn str_works() {
-        fn test_valid(s: &str) {
-            let str_before = String::from(s);
-            let bf_before = str_before.parse::<BF>().expect("Can parse string");
-            let str_after = bf_before.to_string();
-            let bf_after = str_after.parse::<BF>().expect("Can parse string");
-            assert!(str_before == str_after);
-            assert!(bf_before == bf_after);
+    /// Creates boolean function which equals `1` exactly on arguments
+    /// at Hamming distance `radius` from `center`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if args_amount == 0,
+    /// or `BFError::ArgOutOfBounds` if `center >= 2^args_amount` or `radius > args_amount`.
+    pub fn hamming_sphere(args_amount: usize, center: usize, radius: usize) -> Result<Self> {
+        let mut bf = BF::zero(args_amount)?;
+
+        if center >= pow2(args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: center,
+                bounds: pow2(args_amount),
+            });
         }
 
-        test_valid("1111");
-        test_valid("0001");
-        test_valid("10111101");
-        test_valid("1011011101110101");
-        test_valid("10000000000000000000001000000000");
+        if radius > args_amount {
+            return Err(BFError::ArgOutOfBounds {
+                given: radius,
+                bounds: args_amount + 1,
+            });
+        }
 
-        fn test_not_boolen(s: &str) {
-            let res = s.parse::<BF>();
-            match res {
-                Ok(_) => panic!("Should return error"),
-                Err(err) => assert_eq!(err, BFError::InvalidString(s.to_string())),
+        for args in 0..pow2(args_amount) {
+            if weight(args ^ center) == radius {
+                bf.set(args)?;
             }
         }
 
-        test_not_boolen("20");
-        test_not_boolen("3333");
-        test_not_boolen("111s");
+        Ok(bf)
+    }
 
-        fn test_not_pow_two(s: &str) {
-            let res = s.parse::<BF>();
-            match res {
-                Ok(_) => panic!("Should return error"),
-                Err(err) => assert_eq!(err, BFError::NotPowTwo(s.len())),
-            }
+    /// Checks whether arguments, on which function equals `1`,
+    /// form a linear subspace.
+    pub fn support_is_subspace(&self) -> bool {
+        let w = self.weight();
+        if !is_pow2(w) || self.eval(0) == 0 {
+            return false;
         }
 
-        test_not_pow_two("0");
-        test_not_pow_two("111");
-        test_not_pow_two("11111111111111111111111111111");
+        // Support is a subset of its linear span,
+        // so it's a subspace iff their sizes are equal.
+        let bm = self.support_matrix().expect("support is not empty");
+        pow2(bm.rank()) == w
     }
 
-    #[test]
-    fn eval_works() {
-        let bf = BF::from_str("1010110011110000").expect("Can convert");
-        // TODO: iterate over string
-        assert_eq!(bf.eval(0), 1);
-        assert_eq!(bf.eval(1), 0);
-        assert_eq!(bf.eval(2), 1);
-        assert_eq!(bf.eval(8), 1);
-        assert_eq!(bf.eval(9), 1);
-        assert_eq!(bf.eval(14), 0);
-        assert_eq!(bf.eval(15), 0);
+    /// Calculates extended walsh signature of a function: for each distinct absolute value
+    /// of walsh adamar coefficients (in ascending order) amount of masks having it
+    /// and dimension of affine span of these masks.
+    ///
+    /// Affine transformation of arguments maps masks by invertible linear map
+    /// and addition of affine function shifts them, so affine equivalent functions
+    /// have equal signatures. Span dimensions distinguish more classes than multiset
+    /// of walsh coefficients alone.
+    pub fn extended_walsh_signature(&self) -> Vec<(i64, usize, usize)> {
+        let wac = self.walsh_adamar();
+
+        wac.iter()
+            .enumerate()
+            .map(|(w, coef)| (coef.abs(), w))
+            .into_group_map()
+            .into_iter()
+            .sorted()
+            .map(|(coef, masks)| (coef, masks.len(), affine_span_dimension(&masks)))
+            .collect()
     }
 
-    #[test]
-    fn set_works() {
-        fn test_set_unset(bf: &mut BF, args: usize) {
-            assert_eq!(bf.eval(args), 0);
-            bf.set(args).expect("Valid arg");
-            assert_eq!(bf.eval(args), 1);
-            bf.unset(args).expect("Valid arg");
-            assert_eq!(bf.eval(args), 0);
+    /// Flips function values on subcube, where arguments set in `fixed_mask`
+    /// are equal to corresponding bits of `fixed_values`.
+    ///
+    /// # Errors
+    /// Returns `BFError::ArgOutOfBounds` if `fixed_mask >= 2^args_amount`,
+    /// or `BFError::ValuesOutsideMask` if `fixed_values` has bits not set in `fixed_mask`.
+    pub fn flip_subcube(&mut self, fixed_mask: usize, fixed_values: usize) -> Result<()> {
+        if fixed_mask >= pow2(self.args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: fixed_mask,
+                bounds: pow2(self.args_amount),
+            });
         }
 
-        let mut bf = BF::zero(log2(WORD_BIT_SIZE) + 1).expect("Args amount not zero");
-        for i in 0..pow2(bf.args_amount) {
-            test_set_unset(&mut bf, i);
+        if fixed_values & !fixed_mask != 0 {
+            return Err(BFError::ValuesOutsideMask {
+                mask: fixed_mask,
+                values: fixed_values,
+            });
         }
-    }
 
-    #[test]
-    fn mobius_random_reversability() {
-        for i in 0..100 {
-            let mut bf = BF::random(i % 16 + 1).expect("arg amount is not zero");
-            let old = bf.clone();
-            bf.mobius();
-            bf.mobius();
-            println!("{}", i % 16 + 1);
-            assert!(bf == old);
+        // mask of subcube bits inside of a single factor
+        let bits_in_factor = pow2(self.args_amount).min(WORD_BIT_SIZE);
+        let low_mask = mod_ws(fixed_mask);
+        let low_values = mod_ws(fixed_values);
+        let factor_mask = (0..bits_in_factor)
+            .filter(|&bit| bit & low_mask == low_values)
+            .fold(0 as Value, |acc, bit| acc | (1 << bit));
+
+        // factors, which intersect with subcube
+        let high_mask = div_ws(fixed_mask);
+        let high_values = div_ws(fixed_values);
+        for (i, value) in self.values.iter_mut().enumerate() {
+            if i & high_mask == high_values {
+                *value ^= factor_mask;
+            }
         }
-    }
 
-    // #[test]
-    // fn mobius_31_factor_reversability() {
-    //     let mut bf = BF::random(31).expect("arg amount is not zero");
-    //     let old = bf.clone();
-    //     bf.mobius();
-    //     bf.mobius();
-    //     assert!(bf == old);
-    // }
+        Ok(())
+    }
 
-    #[test]
-    fn mobius_transform_const0_anf() {
-        let mut bf = BF::zero(16).unwrap();
-        let anf = bf.anf();
+    /// Calculates amount of monomials in arithmetic normal form of a function.
+    pub fn anf_weight(&self) -> usize {
+        let mut bf_copy = self.clone();
+        bf_copy.mobius().weight()
+    }
 
-        bf.mobius();
-        assert_eq!(bf.to_string(), "0".repeat(pow2(16) as usize));
-        assert_eq!(anf, "0");
+    /// Calculates minimal amount of monomials in arithmetic normal form
+    /// of a function and its inverse.
+    pub fn min_anf_weight(&self) -> usize {
+        self.anf_weight().min(self.inverse().anf_weight())
     }
 
-    #[test]
-    fn mobius_transform_const1_anf() {
-        let mut bf = BF::one(16).unwrap();
-        let anf = bf.anf();
+    /// Calculates sensitivity of a function on argument `args`:
+    /// amount of neighbours of `args` (differing in one bit), on which function value differs.
+    ///
+    /// # Errors
+    /// Returns `BFError::ArgOutOfBounds` if `args >= 2^args_amount`.
+    pub fn sensitivity(&self, args: usize) -> Result<usize> {
+        if args >= pow2(self.args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: args,
+                bounds: pow2(self.args_amount),
+            });
+        }
 
-        bf.mobius();
-        assert_eq!(bf.to_string(), "1".to_owned() + &"0".repeat(pow2(16) - 1));
-        assert_eq!(anf, "1");
+        let value = self.eval(args);
+        Ok((0..self.args_amount)
+            .filter(|&i| self.eval(args ^ pow2(i)) != value)
+            .count())
     }
 
-    #[test]
-    fn anf_works() {
-        let bf = BF::from_str("0001000100011110000100010001111000010001000111101110111011100001")
-            .expect("can convert");
-        assert_eq!(bf.anf(), "x6&x5 + x4&x3 + x2&x1");
-        assert_eq!(bf.deg(), 2);
+    /// Calculates maximal sensitivity of a function over all arguments.
+    pub fn max_sensitivity(&self) -> usize {
+        (0..pow2(self.args_amount))
+            .map(|args| self.sensitivity(args).expect("args is in bounds"))
+            .max()
+            .unwrap_or(0)
+    }
 
-        let mut bf = BF::from_str("11000110").expect("can convert");
-        bf.mobius();
-        assert_eq!(bf.anf(), "1 + x3 + x3&x1 + x2&x1");
+    /// Calculates influence of argument `var`: fraction of arguments,
+    /// on which flipping `var` changes function value.
+    ///
+    /// # Errors
+    /// Returns `BFError::VarOutOfBounds` if `var >= args_amount`.
+    pub fn influence(&self, var: usize) -> Result<f64> {
+        if var >= self.args_amount {
+            return Err(BFError::VarOutOfBounds {
+                given: var,
+                bounds: self.args_amount,
+            });
+        }
 
-        let mut bf = BF::from_str("1111").expect("can convert");
+        let changes = (0..pow2(self.args_amount))
+            .filter(|&args| self.eval(args) != self.eval(args ^ pow2(var)))
+            .count();
+
+        Ok(changes as f64 / pow2(self.args_amount) as f64)
+    }
+
+    /// Calculates average sensitivity (total influence) of a function.
+    pub fn average_sensitivity(&self) -> f64 {
+        let total: usize = (0..pow2(self.args_amount))
+            .map(|args| self.sensitivity(args).expect("args is in bounds"))
+            .sum();
+
+        total as f64 / pow2(self.args_amount) as f64
+    }
+
+    /// Returns function, which arithmetic normal form consists of monomials
+    /// of degree `<= max_deg` from arithmetic normal form of a function.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `max_deg > args_amount`.
+    pub fn truncate_degree(&self, max_deg: usize) -> Result<Self> {
+        if max_deg > self.args_amount {
+            return Err(BFError::InvalidDeg(max_deg));
+        }
+
+        let mut bf = self.clone();
         bf.mobius();
-        assert_eq!(bf.anf(), "1 + x2 + x1 + x2&x1");
+        for args in 0..pow2(self.args_amount) {
+            if weight(args) > max_deg {
+                bf.unset(args)?;
+            }
+        }
+        bf.mobius();
+
+        Ok(bf)
+    }
+
+    /// Finds function of degree `<= max_deg` close to a function.
+    /// Returns found function and distance to it.
+    ///
+    /// Result is exact for `max_deg <= 1` (where it is the best affine approximation)
+    /// and for `max_deg == args_amount`. Otherwise, it's a heuristic:
+    /// the closest of `truncate_degree(max_deg)` and the best affine approximation.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `max_deg > args_amount`.
+    pub fn best_low_degree_approximation(&self, max_deg: usize) -> Result<(Self, usize)> {
+        if max_deg > self.args_amount {
+            return Err(BFError::InvalidDeg(max_deg));
+        }
+
+        if max_deg == 0 {
+            let w = self.weight();
+            if w > pow2(self.args_amount - 1) {
+                return Ok((BF::one(self.args_amount)?, pow2(self.args_amount) - w));
+            }
+            return Ok((BF::zero(self.args_amount)?, w));
+        }
+
+        let affine = self.best_affine_approx();
+        let affine_distance = self.hamming_distance(&affine)?;
+        if max_deg == 1 {
+            return Ok((affine, affine_distance));
+        }
+
+        let truncated = self.truncate_degree(max_deg)?;
+        let truncated_distance = self.hamming_distance(&truncated)?;
+        if truncated_distance < affine_distance {
+            return Ok((truncated, truncated_distance));
+        }
+
+        Ok((affine, affine_distance))
+    }
+
+    /// Returns iterator over masks of all monomials of degree `deg` in ascending order.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `deg > args_amount`.
+    pub fn monomial_masks(&self, deg: usize) -> Result<impl Iterator<Item = usize>> {
+        if deg > self.args_amount {
+            return Err(BFError::InvalidDeg(deg));
+        }
+
+        Ok(BinComb::new(self.args_amount, deg))
+    }
+
+    /// Calculates Chow parameters of a function:
+    /// `[W_f(0), W_f(2^0), ..., W_f(2^(n-1))]`, where `W_f` is walsh adamar coefficient.
+    pub fn chow_parameters(&self) -> Vec<i64> {
+        let wac = self.walsh_adamar();
+
+        std::iter::once(wac[0])
+            .chain((0..self.args_amount).map(|i| wac[pow2(i)]))
+            .collect()
+    }
+
+    /// Checks whether function is a threshold (linearly separable) function,
+    /// i.e. `f(x) = 1` iff `sum w_i x_i >= t` for some integer weights `w_i` and threshold `t`.
+    ///
+    /// Function is normalized to be monotonically increasing by negating decreasing arguments
+    /// (non unate function is never a threshold one), after that all non negative weights
+    /// up to maximal weight of threshold function of `args_amount` arguments are checked.
+    ///
+    /// # Errors
+    /// Returns `BFError::TooManyArgs` if `args_amount > 6`.
+    pub fn is_threshold(&self) -> Result<bool> {
+        // maximal weight required to realize threshold function of n arguments
+        const MAX_WEIGHTS: [usize; 7] = [0, 1, 1, 2, 3, 5, 9];
+
+        let n = self.args_amount;
+        if n >= MAX_WEIGHTS.len() {
+            return Err(BFError::TooManyArgs {
+                args_amount: n,
+                max: MAX_WEIGHTS.len() - 1,
+            });
+        }
+
+        let mut negated = 0;
+        for i in 0..n {
+            let bit = pow2(i);
+            let mut pairs = (0..pow2(n)).filter(|&args| args & bit == 0);
+            let increasing = pairs
+                .clone()
+                .all(|args| self.eval(args) <= self.eval(args | bit));
+            let decreasing = pairs.all(|args| self.eval(args) >= self.eval(args | bit));
+
+            match (increasing, decreasing) {
+                (true, _) => (),
+                (false, true) => negated |= bit,
+                (false, false) => return Ok(false),
+            }
+        }
+
+        // values of monotonically increasing function
+        let values: Vec<u8> = (0..pow2(n)).map(|args| self.eval(args ^ negated)).collect();
+
+        let separable = (0..n)
+            .map(|_| 0..=MAX_WEIGHTS[n])
+            .multi_cartesian_product()
+            .any(|weights| {
+                let sum = |args: usize| -> usize {
+                    (0..n)
+                        .filter(|&i| (args >> i) & 1 == 1)
+                        .map(|i| weights[i])
+                        .sum()
+                };
+
+                let sums = values.iter().enumerate();
+                let max_zero = sums
+                    .clone()
+                    .filter(|(_, &v)| v == 0)
+                    .map(|(a, _)| sum(a))
+                    .max();
+                let min_one = sums.filter(|(_, &v)| v == 1).map(|(a, _)| sum(a)).min();
+
+                match (max_zero, min_one) {
+                    (Some(zero), Some(one)) => zero < one,
+                    _ => true,
+                }
+            });
+
+        Ok(separable)
+    }
+
+    /// Returns function `g(x) = f(Ax ^ b)`, where `A` is `matrix` and `b` is `shift`.
+    /// Bit `i` of `Ax` is a sum of bits `j` of `x`, for which `matrix.get(i, j) == 1`.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if `matrix` is not a square matrix of size `args_amount`,
+    /// or `BFError::ArgOutOfBounds` if `shift >= 2^args_amount`.
+    pub fn compose_affine(&self, matrix: &BM, shift: usize) -> Result<Self> {
+        let n = self.args_amount;
+        for given in [matrix.rows(), matrix.cols()] {
+            if given != n {
+                return Err(BFError::DimMismatch { expected: n, given });
+            }
+        }
+
+        if shift >= pow2(n) {
+            return Err(BFError::ArgOutOfBounds {
+                given: shift,
+                bounds: pow2(n),
+            });
+        }
+
+        let rows: Vec<usize> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| matrix.get(i, j) == 1)
+                    .fold(0, |acc, j| acc | pow2(j))
+            })
+            .collect();
+
+        let mut bf = BF::zero(n)?;
+        for args in 0..pow2(n) {
+            let image = rows
+                .iter()
+                .enumerate()
+                .filter(|(_, &row)| weight(row & args) & 1 == 1)
+                .fold(0, |acc, (i, _)| acc | pow2(i));
+
+            if self.eval(image ^ shift) == 1 {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Returns canonical representative of affine equivalence class of a function:
+    /// function with lexicographically minimal truth table (as given by `to_string`)
+    /// among all `f(Ax ^ b)` and `f(Ax ^ b) ^ 1` for invertible `A`.
+    ///
+    /// # Errors
+    /// Returns `BFError::TooManyArgs` if `args_amount > 4`.
+    pub fn affine_class_representative(&self) -> Result<Self> {
+        const MAX_ARGS: usize = 4;
+
+        let n = self.args_amount;
+        if n > MAX_ARGS {
+            return Err(BFError::TooManyArgs {
+                args_amount: n,
+                max: MAX_ARGS,
+            });
+        }
+
+        let is_invertible = |rows: &[usize]| -> bool {
+            let mut rows = rows.to_vec();
+            for col in 0..n {
+                let bit = pow2(col);
+                let Some(pivot) = (col..n).find(|&i| rows[i] & bit != 0) else {
+                    return false;
+                };
+                rows.swap(col, pivot);
+                for i in col + 1..n {
+                    if rows[i] & bit != 0 {
+                        rows[i] ^= rows[col];
+                    }
+                }
+            }
+            true
+        };
+
+        // truth tables are stored with first argument as the most significant bit,
+        // so that their order is the same as of strings
+        let full = (1u32 << pow2(n)) - 1;
+        let mut best = u32::MAX;
+        for rows in (0..n).map(|_| 0..pow2(n)).multi_cartesian_product() {
+            if !is_invertible(&rows) {
+                continue;
+            }
+
+            let images: Vec<usize> = (0..pow2(n))
+                .map(|args| {
+                    (0..n)
+                        .filter(|&i| weight(rows[i] & args) & 1 == 1)
+                        .fold(0, |acc, i| acc | pow2(i))
+                })
+                .collect();
+
+            for shift in 0..pow2(n) {
+                let table = images
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &image)| self.eval(image ^ shift) == 1)
+                    .fold(0u32, |acc, (args, _)| acc | (1 << (pow2(n) - 1 - args)));
+
+                best = best.min(table).min(table ^ full);
+            }
+        }
+
+        // first argument is the most significant bit of `best`
+        let mut bf = BF::zero(n)?;
+        for args in 0..pow2(n) {
+            if (best >> (pow2(n) - 1 - args)) & 1 == 1 {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Returns all arguments in ascending order, on which function equals `0`.
+    pub fn off_set(&self) -> Vec<usize> {
+        (0..pow2(self.args_amount))
+            .filter(|&args| self.eval(args) == 0)
+            .collect()
+    }
+
+    /// Creates function of `generator.cols()` arguments, which equals `1` exactly
+    /// on vectors of row space of `generator`. Column `j` corresponds to argument bit `j`.
+    ///
+    /// # Errors
+    /// Returns errors of `BF::zero` for `generator.cols()` arguments.
+    pub fn indicator_of_code(generator: &BM) -> Result<Self> {
+        let n = generator.cols();
+        let mut bf = BF::zero(n)?;
+
+        let mut echelon = generator.clone();
+        echelon.gaussian_elimination();
+        let basis: Vec<usize> = (0..echelon.rows())
+            .map(|row| {
+                (0..n)
+                    .filter(|&col| echelon.get(row, col) == 1)
+                    .fold(0, |acc, col| acc | pow2(col))
+            })
+            .filter(|&row| row != 0)
+            .collect();
+
+        for coefs in 0..pow2(basis.len()) {
+            let word = basis
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (coefs >> i) & 1 == 1)
+                .fold(0, |acc, (_, &row)| acc ^ row);
+            bf.set(word)?;
+        }
+
+        Ok(bf)
+    }
+
+    /// Calculates heuristic diffusion score of a function in `[0, 1]` (higher is better).
+    ///
+    /// Score is an average of three equally weighted terms:
+    /// * `nonlinearity / (2^(n-1) - 2^(n/2-1))` -- nonlinearity relative to its upper bound;
+    /// * `1 - ai / 2^n`, where `ai` is maximal absolute autocorrelation over nonzero shifts;
+    /// * `average_sensitivity / n`.
+    pub fn diffusion_score(&self) -> f64 {
+        let n = self.args_amount as f64;
+        let size = pow2(self.args_amount) as f64;
+
+        let nl_bound = size / 2.0 - 2f64.powf(n / 2.0 - 1.0);
+        let nl_score = (self.nonlinearity() as f64 / nl_bound).min(1.0);
+
+        let ai_score = 1.0 - self.absolute_indicator() as f64 / size;
+
+        let sensitivity_score = self.average_sensitivity() / n;
+
+        (nl_score + ai_score + sensitivity_score) / 3.0
+    }
+
+    /// Returns monomials of arithmetic normal form, which are not contained in other ones.
+    /// Monomial is given by indices of its variables, named the same way as in `anf`
+    /// (e.g. `[2, 1]` for `x2&x1`). Constant `1` is given by empty vector.
+    pub fn maximal_monomials(&self) -> Vec<Vec<usize>> {
+        let mut bf_copy = self.clone();
+        let bf_mob = bf_copy.mobius();
+
+        let monomials: Vec<usize> = (0..pow2(self.args_amount))
+            .filter(|&mask| bf_mob.eval(mask) == 1)
+            .collect();
+
+        monomials
+            .iter()
+            .filter(|&&mask| {
+                !monomials
+                    .iter()
+                    .any(|&other| other != mask && other & mask == mask)
+            })
+            .map(|&mask| self.monomial_vars(mask))
+            .collect()
+    }
+
+    /// Returns indices of variables of monomial `mask` as they are named in `anf`.
+    fn monomial_vars(&self, mask: usize) -> Vec<usize> {
+        (0..self.args_amount)
+            .filter(|&i| (mask >> i) & 1 == 1)
+            .map(|i| self.args_amount - i)
+            .collect()
+    }
+
+    /// Estimates fraction of arguments, on which function equals `1`,
+    /// by evaluating function on `samples` uniformly random arguments.
+    /// Returns estimate and its standard error.
+    ///
+    /// Both values are `NaN` if `samples == 0`.
+    pub fn estimated_bias(&self, samples: usize) -> (f64, f64) {
+        let rng = rand::thread_rng();
+        let uniform = Uniform::new(0, pow2(self.args_amount));
+        let ones = rng
+            .sample_iter(uniform)
+            .take(samples)
+            .filter(|&args| self.eval(args) == 1)
+            .count();
+
+        let p = ones as f64 / samples as f64;
+        let std_err = (p * (1.0 - p) / samples as f64).sqrt();
+
+        (p, std_err)
+    }
+
+    /// Returns function of `args_amount - 1` arguments, which truth table consists of
+    /// values of a function on arguments of even (or odd) weight in ascending order.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `args_amount == 1`.
+    pub fn restrict_to_parity(&self, even: bool) -> Result<Self> {
+        let mut bf = BF::zero(self.args_amount - 1)?;
+
+        let parity = if even { 0 } else { 1 };
+        for (i, args) in (0..pow2(self.args_amount))
+            .filter(|&args| weight(args) & 1 == parity)
+            .enumerate()
+        {
+            if self.eval(args) == 1 {
+                bf.set(i)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Calculates sum of squared walsh adamar coefficients over masks of weight `k`.
+    ///
+    /// Function is `k` correlation immune iff energies on levels `1..=k` are all zero.
+    /// Squares reach `2^(2n)`, so energy is accumulated in `u128`.
+    pub fn walsh_energy_at_level(&self, k: usize) -> u128 {
+        let wac = self.walsh_adamar();
+
+        BinComb::new(self.args_amount, k)
+            .map(|mask| wac[mask].unsigned_abs() as u128)
+            .map(|coef| coef * coef)
+            .sum()
+    }
+
+    /// Creates direct sum of `times` copies of `seed` on disjoint arguments:
+    /// `h(x_1, ..., x_times) = seed(x_1) ^ ... ^ seed(x_times)`,
+    /// where `x_1` is formed by the least significant `seed.args_amount` bits of argument.
+    ///
+    /// If seed is bent, result is bent too.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `times == 0`,
+    /// `BFError::TooManyArgs` if resulting amount of arguments is greater than `MAX_ARGS`.
+    pub fn direct_sum_power(seed: &BF, times: usize) -> Result<Self> {
+        if times == 0 {
+            return Err(BFError::NoArgs);
+        }
+
+        (1..times).try_fold(seed.clone(), |bf, _| bf.direct_sum(seed))
+    }
+
+    /// Returns masks of monomials of degree `<= max_deg` in arithmetic normal form
+    /// in ascending order.
+    ///
+    /// Every coefficient is calculated separately as a sum of function values
+    /// over submasks, which is faster than full Mobius transform for small `max_deg`.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `max_deg > args_amount`.
+    pub fn mobius_up_to_degree(&self, max_deg: usize) -> Result<Vec<usize>> {
+        if max_deg > self.args_amount {
+            return Err(BFError::InvalidDeg(max_deg));
+        }
+
+        let coef = |mask: usize| -> u8 {
+            let mut sum = self.eval(0);
+            let mut sub = mask;
+            while sub != 0 {
+                sum ^= self.eval(sub);
+                sub = (sub - 1) & mask;
+            }
+            sum
+        };
+
+        let monomials = (0..=max_deg)
+            .flat_map(|d| BinComb::new(self.args_amount, d))
+            .filter(|&mask| coef(mask) == 1)
+            .sorted()
+            .collect();
+
+        Ok(monomials)
+    }
+
+    /// Returns coefficients of arithmetic normal form of a function:
+    /// `i`-th element is `true` iff monomial with mask `i` is present.
+    pub fn anf_coefficients(&self) -> Vec<bool> {
+        let mut bf_copy = self.clone();
+        let bf_mob = bf_copy.mobius();
+
+        (0..pow2(self.args_amount))
+            .map(|mask| bf_mob.eval(mask) == 1)
+            .collect()
+    }
+
+    /// Returns function of `args_amount - 1` arguments formed by the lower half
+    /// of the truth table, i.e. function with the most significant argument set to `0`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `args_amount == 1`.
+    pub fn lower_half(&self) -> Result<Self> {
+        self.half(false)
+    }
+
+    /// Returns function of `args_amount - 1` arguments formed by the upper half
+    /// of the truth table, i.e. function with the most significant argument set to `1`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `args_amount == 1`.
+    pub fn upper_half(&self) -> Result<Self> {
+        self.half(true)
+    }
+
+    fn half(&self, upper: bool) -> Result<Self> {
+        let mut bf = BF::zero(self.args_amount - 1)?;
+
+        let half_bits = pow2(self.args_amount - 1);
+        if half_bits >= WORD_BIT_SIZE {
+            let half_len = self.values.len() / 2;
+            let start = if upper { half_len } else { 0 };
+            bf.values
+                .copy_from_slice(&self.values[start..start + half_len]);
+        } else {
+            let shift = if upper { half_bits } else { 0 };
+            bf.values[0] = (self.values[0] >> shift) & ((1 << half_bits) - 1);
+        }
+
+        Ok(bf)
+    }
+
+    /// Calculates cross correlation of functions for all shifts `a`:
+    /// `C(a) = sum (-1)^(f(x) ^ g(x ^ a))`.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn cross_correlation(&self, other: &BF) -> Result<Vec<i64>> {
+        if self.args_amount != other.args_amount {
+            return Err(BFError::DimMismatch {
+                expected: self.args_amount,
+                given: other.args_amount,
+            });
+        }
+
+        Ok(inverse_walsh_of_product(
+            &self.walsh_adamar(),
+            &other.walsh_adamar(),
+            self.args_amount,
+        ))
+    }
+
+    /// Returns truth tables of all monomials of arithmetic normal form of a function
+    /// in the same order as in `anf`. Sum of them equals to the function.
+    pub fn monomial_functions(&self) -> Vec<Self> {
+        let mut bf_copy = self.clone();
+        let bf_mob = bf_copy.mobius();
+
+        (0..pow2(self.args_amount))
+            .filter(|&mask| bf_mob.eval(mask) == 1)
+            .map(|mask| {
+                let mut monomial = BF::zero(self.args_amount).expect("args_amount is not zero");
+                for args in (0..pow2(self.args_amount)).filter(|&args| args & mask == mask) {
+                    monomial.set(args).expect("args is in bounds");
+                }
+                monomial
+            })
+            .collect()
+    }
+
+    /// Calculates weight of the lower half of the truth table
+    /// (arguments with the most significant bit set to `0`).
+    pub fn weight_of_lower_half(&self) -> usize {
+        self.half_weight(false)
+    }
+
+    /// Calculates weight of the upper half of the truth table
+    /// (arguments with the most significant bit set to `1`).
+    pub fn weight_of_upper_half(&self) -> usize {
+        self.half_weight(true)
+    }
+
+    fn half_weight(&self, upper: bool) -> usize {
+        let half_bits = pow2(self.args_amount - 1);
+        if half_bits >= WORD_BIT_SIZE {
+            let half_len = self.values.len() / 2;
+            let start = if upper { half_len } else { 0 };
+            return self.values[start..start + half_len]
+                .iter()
+                .fold(0, |acc, &factor| acc + factor.count_ones() as usize);
+        }
+
+        let shift = if upper { half_bits } else { 0 };
+        weight(((self.values[0] >> shift) & ((1 << half_bits) - 1)) as usize)
+    }
+
+    /// Evaluates function on argument given as values of variables `x1..xn`,
+    /// named the same way as in `anf`: variable `xk` is the bit `n - k` of argument,
+    /// so `x1` is the most significant bit and `xn` is the least significant one.
+    ///
+    /// # Errors
+    /// Returns `BFError::VarOutOfBounds` if variable index is not in `1..=n`,
+    /// `BFError::InvalidBit` if variable value is not `0` or `1`,
+    /// or `BFError::MissingVar` if some variable is not given.
+    pub fn eval_assignment(&self, assignment: &HashMap<usize, u8>) -> Result<u8> {
+        let n = self.args_amount;
+
+        for (&var, &value) in assignment.iter().sorted() {
+            if var == 0 || var > n {
+                return Err(BFError::VarOutOfBounds {
+                    given: var,
+                    bounds: n,
+                });
+            }
+
+            if value > 1 {
+                return Err(BFError::InvalidBit(value));
+            }
+        }
+
+        let mut args = 0;
+        for var in 1..=n {
+            match assignment.get(&var) {
+                Some(1) => args |= pow2(n - var),
+                Some(_) => (),
+                None => return Err(BFError::MissingVar(var)),
+            }
+        }
+
+        Ok(self.eval(args))
+    }
+
+    /// Calculates walsh adamar coefficients of a function of `args_amount - 1` arguments,
+    /// obtained by fixing argument `var` to `value`, without its truth table.
+    ///
+    /// If `w0` and `w1` are masks `w` with bit `var` inserted as `0` and `1` respectively,
+    /// then `W_f(w0) = W_f0(w) + W_f1(w)` and `W_f(w1) = W_f0(w) - W_f1(w)`,
+    /// so restricted coefficients are half sum (or half difference) of parent ones.
+    ///
+    /// # Errors
+    /// Returns `BFError::VarOutOfBounds` if `var >= args_amount`,
+    /// `BFError::InvalidBit` if `value > 1`, or `BFError::NoArgs` if `args_amount == 1`.
+    pub fn restricted_walsh(&self, var: usize, value: u8) -> Result<Vec<i64>> {
+        if var >= self.args_amount {
+            return Err(BFError::VarOutOfBounds {
+                given: var,
+                bounds: self.args_amount,
+            });
+        }
+
+        if value > 1 {
+            return Err(BFError::InvalidBit(value));
+        }
+
+        if self.args_amount == 1 {
+            return Err(BFError::NoArgs);
+        }
+
+        let wac = self.walsh_adamar();
+        let low_mask = pow2(var) - 1;
+        let restricted = (0..pow2(self.args_amount - 1))
+            .map(|w| {
+                let w0 = (w & low_mask) | ((w & !low_mask) << 1);
+                let w1 = w0 | pow2(var);
+                match value {
+                    0 => (wac[w0] + wac[w1]) / 2,
+                    _ => (wac[w0] - wac[w1]) / 2,
+                }
+            })
+            .collect();
+
+        Ok(restricted)
+    }
+
+    /// Returns sum of functions over GF(2).
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn xor(&self, other: &BF) -> Result<Self> {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    /// Returns pointwise conjunction of functions.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn try_and(&self, other: &BF) -> Result<Self> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Returns pointwise disjunction of functions.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn try_or(&self, other: &BF) -> Result<Self> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Applies `op` to corresponding factors of functions.
+    /// `op` should map zero factors to zero, so that unused bits stay zero.
+    fn combine(&self, other: &BF, op: impl Fn(Value, Value) -> Value) -> Result<Self> {
+        if self.args_amount != other.args_amount {
+            return Err(BFError::DimMismatch {
+                expected: self.args_amount,
+                given: other.args_amount,
+            });
+        }
+
+        let values = self
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(&a, &b)| op(a, b))
+            .collect();
+
+        Ok(BF {
+            values,
+            args_amount: self.args_amount,
+        })
+    }
+
+    /// Returns function of weight `target_weight` at minimal distance from a function,
+    /// i.e. `|weight - target_weight|`. Values on the smallest arguments are flipped.
+    ///
+    /// # Errors
+    /// Returns `BFError::ArgOutOfBounds` if `target_weight > 2^args_amount`.
+    pub fn balance_by_flips(&self, target_weight: usize) -> Result<Self> {
+        if target_weight > pow2(self.args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: target_weight,
+                bounds: pow2(self.args_amount) + 1,
+            });
+        }
+
+        let mut bf = self.clone();
+        let w = self.weight();
+        if w > target_weight {
+            for args in (0..pow2(self.args_amount))
+                .filter(|&args| self.eval(args) == 1)
+                .take(w - target_weight)
+            {
+                bf.unset(args)?;
+            }
+        } else {
+            for args in (0..pow2(self.args_amount))
+                .filter(|&args| self.eval(args) == 0)
+                .take(target_weight - w)
+            {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Checks whether function of `n = 2m` arguments has Maiorana-McFarland form
+    /// `f(x, y) = <x, pi(y)> ^ g(y)` for some permutation `pi`, where `x` is formed
+    /// by the `m` least significant bits of argument and `y` by the `m` most significant ones.
+    ///
+    /// Every restriction `x -> f(x, y)` should be affine with linear part `pi(y)`,
+    /// and all linear parts should be different.
+    pub fn is_maiorana_mcfarland(&self) -> bool {
+        if self.args_amount & 1 == 1 {
+            return false;
+        }
+
+        let m = self.args_amount / 2;
+        let mut seen = vec![false; pow2(m)];
+        for y in 0..pow2(m) {
+            let mut char_vec: Vec<i64> = (0..pow2(m))
+                .map(|x| 1 - 2 * self.eval(x | (y << m)) as i64)
+                .collect();
+            fast_hadamard_transform(&mut char_vec);
+
+            // restriction is affine iff its spectrum has single nonzero coefficient
+            let mut nonzero = char_vec.iter().positions(|&coef| coef != 0);
+            let (Some(linear), None) = (nonzero.next(), nonzero.next()) else {
+                return false;
+            };
+
+            if seen[linear] {
+                return false;
+            }
+            seen[linear] = true;
+        }
+
+        true
+    }
+
+    /// Returns complement of a function (the same as `inverse`).
+    pub fn complement(&self) -> Self {
+        self.inverse()
+    }
+
+    /// Calculates distance to the closest symmetric function.
+    ///
+    /// Symmetric function is constant on every weight level, so the closest one
+    /// takes the majority value on each level, and distance is the sum of minorities.
+    pub fn distance_to_symmetric(&self) -> usize {
+        let n = self.args_amount;
+
+        let mut ones = vec![0; n + 1];
+        for args in 0..pow2(n) {
+            ones[weight(args)] += self.eval(args) as usize;
+        }
+
+        ones.iter()
+            .enumerate()
+            .map(|(k, &count)| count.min(comb(n, k) - count))
+            .sum()
+    }
+
+    /// Calculates amount of arguments on which functions differ.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn hamming_distance(&self, other: &BF) -> Result<usize> {
+        if self.args_amount != other.args_amount {
+            return Err(BFError::DimMismatch {
+                expected: self.args_amount,
+                given: other.args_amount,
+            });
+        }
+
+        // NOTE: function assumes that unused bits in value set to zero.
+        let distance = self
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum();
+
+        Ok(distance)
+    }
+
+    /// Calculates lower bound of degree using divisibility of Walsh coefficients.
+    ///
+    /// By McEliece theorem, Walsh coefficients of a function of degree `d >= 1`
+    /// are divisible by `2^ceil(n/d)`. So if `2^k` is the largest power of two
+    /// dividing all coefficients, then `deg >= ceil(n/k)`.
+    /// Returns 0 for constant functions.
+    pub fn degree_lower_bound_from_walsh(&self) -> usize {
+        if self.is_constant().is_some() {
+            return 0;
+        }
+
+        let k = self
+            .walsh_adamar()
+            .iter()
+            .filter(|&&w| w != 0)
+            .map(|w| w.trailing_zeros() as usize)
+            .min()
+            .expect("spectrum is not zero by Parseval's identity");
+
+        self.args_amount.div_ceil(k)
+    }
+
+    /// Builds function of `args_amount` arguments from its algebraic normal form
+    /// in format of `BF::anf()`, e.g. `"1 + x2 + x3&x1"`.
+    /// Monomials occurring several times cancel each other out.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidAnf` if monomial can't be parsed,
+    /// `BFError::VarOutOfBounds` if variable index is not in `1..=args_amount`.
+    pub fn from_anf(s: &str, args_amount: usize) -> Result<Self> {
+        let mut bf = BF::zero(args_amount)?;
+
+        for monomial in s.split('+').map(str::trim) {
+            if monomial == "0" {
+                continue;
+            }
+
+            let mut mask = 0;
+            if monomial != "1" {
+                for var in monomial.split('&').map(str::trim) {
+                    let index: usize = var
+                        .strip_prefix('x')
+                        .and_then(|index| index.parse().ok())
+                        .ok_or_else(|| BFError::InvalidAnf(monomial.to_string()))?;
+
+                    if index == 0 || index > args_amount {
+                        return Err(BFError::VarOutOfBounds {
+                            given: index,
+                            bounds: args_amount,
+                        });
+                    }
+
+                    mask |= pow2(args_amount - index);
+                }
+            }
+
+            bf.values[div_ws(mask)] ^= 1 << mod_ws(mask);
+        }
+
+        bf.mobius();
+        Ok(bf)
+    }
+
+    /// Checks if function takes values `0` and `1` equally often.
+    pub fn is_balanced(&self) -> bool {
+        self.weight() == pow2(self.args_amount - 1)
+    }
+
+    /// Returns nonzero linear structures of a function in ascending order:
+    /// shifts `a`, for which derivative `f(x) ^ f(x ^ a)` is constant.
+    pub fn linear_structures(&self) -> Vec<usize> {
+        let acv = self.autocor();
+
+        (1..pow2(self.args_amount))
+            .filter(|&a| acv[a].unsigned_abs() as usize == pow2(self.args_amount))
+            .collect()
+    }
+
+    /// Calculates dimension of linear space formed by linear structures of a function.
+    pub fn linear_structure_dimension(&self) -> usize {
+        let structures = self.linear_structures();
+        if structures.is_empty() {
+            return 0;
+        }
+
+        let mut bm = BM::zero(structures.len(), self.args_amount).expect("dimensions are not zero");
+        for (row, a) in structures.iter().enumerate() {
+            for col in (0..self.args_amount).filter(|&col| (a >> col) & 1 == 1) {
+                bm.set(row, col);
+            }
+        }
+
+        bm.rank()
+    }
+
+    /// Returns hex encoded 64-bit FNV-1a hash of sorted absolute values of walsh adamar
+    /// coefficients.
+    ///
+    /// Digest is stable between runs and doesn't change under affine transformation
+    /// of arguments, addition of affine function and negation,
+    /// so affine equivalent functions have equal digests.
+    pub fn walsh_digest(&self) -> String {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let mut wac: Vec<u64> = self
+            .walsh_adamar()
+            .iter()
+            .map(|coef| coef.unsigned_abs())
+            .collect();
+        wac.sort_unstable();
+
+        let hash = wac
+            .iter()
+            .flat_map(|coef| coef.to_le_bytes())
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            });
+
+        format!("{hash:016x}")
+    }
+
+    /// Checks if function is bent: all walsh adamar coefficients
+    /// have absolute value `2^(n/2)`. Bent functions exist only for even `n`.
+    pub fn is_bent(&self) -> bool {
+        if self.args_amount & 1 == 1 {
+            return false;
+        }
+
+        let bound = pow2(self.args_amount / 2);
+        self.walsh_adamar()
+            .iter()
+            .all(|coef| coef.unsigned_abs() as usize == bound)
+    }
+
+    /// Calculates absolute indicator of a function: maximal absolute value
+    /// of autocorrelation over nonzero shifts. The lower, the better function
+    /// resists differential attacks; it's `0` only for bent functions.
+    pub fn absolute_indicator(&self) -> i64 {
+        self.autocor()
+            .iter()
+            .skip(1)
+            .map(|coef| coef.abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns function, in which variable `target` is replaced by `target ^ source`.
+    ///
+    /// # Errors
+    /// Returns `BFError::VarOutOfBounds` if `target` or `source` is not less than `args_amount`,
+    /// `BFError::DuplicateVar` if `target == source`.
+    pub fn substitute_xor(&self, target: usize, source: usize) -> Result<Self> {
+        let n = self.args_amount;
+        for var in [target, source] {
+            if var >= n {
+                return Err(BFError::VarOutOfBounds {
+                    given: var,
+                    bounds: n,
+                });
+            }
+        }
+
+        if target == source {
+            return Err(BFError::DuplicateVar(target));
+        }
+
+        let mut matrix = BM::zero(n, n).expect("dimensions are not zero");
+        for i in 0..n {
+            matrix.set(i, i);
+        }
+        matrix.set(target, source);
+
+        self.compose_affine(&matrix, 0)
+    }
+
+    /// Calculates sum-of-squares indicator of a function: `sum C(a)^2` over all shifts `a`,
+    /// where `C` is autocorrelation spectrum.
+    ///
+    /// By identity `sum C(a)^2 = 2^(-n) * sum W(w)^4`, where `W` is walsh adamar spectrum,
+    /// indicator is `2^(2n)` for bent functions (the minimum) and `2^(3n)` for affine ones.
+    /// Sum doesn't overflow for `n <= 21`.
+    pub fn sum_of_squares_indicator(&self) -> u64 {
+        self.autocor()
+            .iter()
+            .map(|&coef| (coef * coef) as u64)
+            .sum()
+    }
+
+    /// Returns iterator over all functions of `args_amount` arguments with given weight,
+    /// ordered by their supports as in `BinComb`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `args_amount == 0`,
+    /// `BFError::TooManyArgs` if `args_amount > 5`,
+    /// `BFError::ArgOutOfBounds` if `weight > 2^args_amount`.
+    pub fn all_with_weight(args_amount: usize, weight: usize) -> Result<impl Iterator<Item = BF>> {
+        // supports are enumerated as masks of 2^args_amount bits
+        const MAX_ARGS: usize = 5;
+
+        let zero = BF::zero(args_amount)?;
+        if args_amount > MAX_ARGS {
+            return Err(BFError::TooManyArgs {
+                args_amount,
+                max: MAX_ARGS,
+            });
+        }
+
+        let size = pow2(args_amount);
+        if weight > size {
+            return Err(BFError::ArgOutOfBounds {
+                given: weight,
+                bounds: size + 1,
+            });
+        }
+
+        Ok(BinComb::new(size, weight).map(move |support| {
+            let mut bf = zero.clone();
+            for args in (0..size).filter(|&args| (support >> args) & 1 == 1) {
+                bf.set(args).expect("args is in bounds");
+            }
+            bf
+        }))
+    }
+
+    /// Calculates population variance of absolute values of walsh adamar coefficients,
+    /// which measures spectrum flatness.
+    ///
+    /// By Parseval's identity mean of squares is `2^n`, so variance is `2^n - mean(|W|)^2`.
+    /// It's `0` only for bent functions and reaches `2^n - 1` for affine ones.
+    /// Variance of signed coefficients isn't used as it always equals `2^n - 1`.
+    pub fn walsh_abs_variance(&self) -> f64 {
+        let wac = self.walsh_adamar();
+        let size = wac.len() as f64;
+
+        let mean = wac
+            .iter()
+            .map(|coef| coef.unsigned_abs() as f64)
+            .sum::<f64>()
+            / size;
+
+        size - mean * mean
+    }
+
+    /// Calculates algebraic immunity of a function: minimal degree of nonzero function `g`,
+    /// such that `g * f = 0` or `g * (1 ^ f) = 0`. Returns `0` for constant functions.
+    ///
+    /// Annihilator of degree `<= d` exists iff columns of monomial matrix
+    /// (see `BM::monomial`) built on the support are linearly dependent.
+    pub fn algebraic_immunity(&self) -> usize {
+        if self.is_constant().is_some() {
+            return 0;
+        }
+
+        let has_annihilator = |bf: &BF, deg: usize| -> bool {
+            let bm = BM::monomial(bf, deg).expect("deg is in bounds and bf is not zero");
+            bm.rank() < bm.cols()
+        };
+
+        let inverse = self.inverse();
+        (1..=self.args_amount)
+            .find(|&deg| has_annihilator(self, deg) || has_annihilator(&inverse, deg))
+            .expect("there is annihilator of degree args_amount")
+    }
+
+    /// Calculates resiliency order of a function: correlation immunity order
+    /// for balanced function, `-1` for unbalanced.
+    pub fn resiliency(&self) -> i32 {
+        if !self.is_balanced() {
+            return -1;
+        }
+
+        self.cor() as i32
+    }
+
+    /// Returns values of a function in Gray code order: `i`-th element is `f(gray(i))`,
+    /// so successive arguments differ in one bit.
+    pub fn to_gray_ordered(&self) -> Vec<u8> {
+        (0..pow2(self.args_amount))
+            .map(|i| self.eval(gray(i)))
+            .collect()
+    }
+
+    /// Returns derivative of a function in direction `a`: `D_a f(x) = f(x) ^ f(x ^ a)`.
+    ///
+    /// # Errors
+    /// Returns `BFError::ArgOutOfBounds` if `a >= 2^args_amount`.
+    pub fn derivative(&self, a: usize) -> Result<Self> {
+        if a >= pow2(self.args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: a,
+                bounds: pow2(self.args_amount),
+            });
+        }
+
+        // shift moves whole factors, so they can be xored
+        if mod_ws(a) == 0 {
+            let values = (0..self.values.len())
+                .map(|i| self.values[i] ^ self.values[i ^ div_ws(a)])
+                .collect();
+
+            return Ok(BF {
+                values,
+                args_amount: self.args_amount,
+            });
+        }
+
+        let mut bf = BF::zero(self.args_amount)?;
+        for args in 0..pow2(self.args_amount) {
+            if self.eval(args) != self.eval(args ^ a) {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Returns minimized sum of products (disjunctive normal form) of a function,
+    /// found by Quine-McCluskey algorithm, e.g. `"x3&x2 + x3&x1 + x2&x1"`.
+    /// Here `+` is disjunction and `!` is negation, variables are named as in `anf`.
+    ///
+    /// Cover consists of essential prime implicants, completed greedily
+    /// by prime implicants covering most of remaining arguments,
+    /// so it's minimal unless function has cyclic core.
+    ///
+    /// # Errors
+    /// Returns `BFError::TooManyArgs` if `args_amount > 8`.
+    pub fn to_sop_minimal(&self) -> Result<String> {
+        let primes = self.prime_implicants()?;
+        match self.is_constant() {
+            Some(0) => return Ok(String::from("0")),
+            Some(_) => return Ok(String::from("1")),
+            None => (),
+        }
+
+        let covers = |&(bits, care): &(usize, usize), args: usize| args & care == bits;
+
+        let mut uncovered: Vec<usize> = (0..pow2(self.args_amount))
+            .filter(|&args| self.eval(args) == 1)
+            .collect();
+
+        // essential implicants are the only ones covering some argument
+        let mut cover: Vec<(usize, usize)> = uncovered
+            .iter()
+            .filter_map(|&args| {
+                let mut covering = primes.iter().filter(|prime| covers(prime, args));
+                match (covering.next(), covering.next()) {
+                    (Some(&prime), None) => Some(prime),
+                    _ => None,
+                }
+            })
+            .unique()
+            .collect();
+        uncovered.retain(|&args| !cover.iter().any(|prime| covers(prime, args)));
+
+        while !uncovered.is_empty() {
+            let &best = primes
+                .iter()
+                .max_by_key(|&prime| {
+                    let covered = uncovered
+                        .iter()
+                        .filter(|&&args| covers(prime, args))
+                        .count();
+                    (covered, usize::MAX - weight(prime.1))
+                })
+                .expect("primes cover the whole support");
+
+            cover.push(best);
+            uncovered.retain(|&args| !covers(&best, args));
+        }
+
+        cover.sort_by_key(|&(bits, care)| (care, bits));
+        let sop = cover
+            .iter()
+            .map(|&(bits, care)| {
+                self.monomial_vars(care)
+                    .into_iter()
+                    .map(|var| {
+                        let negation = if (bits >> (self.args_amount - var)) & 1 == 0 {
+                            "!"
+                        } else {
+                            ""
+                        };
+                        format!("{negation}x{var}")
+                    })
+                    .join("&")
+            })
+            .join(" + ");
+
+        Ok(sop)
+    }
+
+    /// Calculates amount of prime implicants of a function:
+    /// products of literals implying a function, which can't be shortened.
+    ///
+    /// # Errors
+    /// Returns `BFError::TooManyArgs` if `args_amount > 8`.
+    pub fn prime_implicant_count(&self) -> Result<usize> {
+        Ok(self.prime_implicants()?.len())
+    }
+
+    // Finds prime implicants by Quine-McCluskey algorithm.
+    // Implicant is given as (bits, care): it covers `args` iff `args & care == bits`.
+    fn prime_implicants(&self) -> Result<Vec<(usize, usize)>> {
+        const MAX_ARGS: usize = 8;
+        if self.args_amount > MAX_ARGS {
+            return Err(BFError::TooManyArgs {
+                args_amount: self.args_amount,
+                max: MAX_ARGS,
+            });
+        }
+
+        let full_care = pow2(self.args_amount) - 1;
+        let mut implicants: Vec<(usize, usize)> = (0..pow2(self.args_amount))
+            .filter(|&args| self.eval(args) == 1)
+            .map(|args| (args, full_care))
+            .collect();
+
+        let mut primes = Vec::new();
+        while !implicants.is_empty() {
+            let mut merged = vec![false; implicants.len()];
+            let mut next = Vec::new();
+
+            // implicants differing in one cared bit merge into a shorter one
+            for i in 0..implicants.len() {
+                for j in (i + 1)..implicants.len() {
+                    let ((a, care_a), (b, care_b)) = (implicants[i], implicants[j]);
+                    if care_a == care_b && is_pow2(a ^ b) {
+                        merged[i] = true;
+                        merged[j] = true;
+                        next.push((a & b, care_a & !(a ^ b)));
+                    }
+                }
+            }
+
+            primes.extend(
+                implicants
+                    .iter()
+                    .zip(merged)
+                    .filter(|&(_, merged)| !merged)
+                    .map(|(&implicant, _)| implicant),
+            );
+            implicants = next.into_iter().unique().collect();
+        }
+
+        Ok(primes)
+    }
+
+    /// Returns function of `args_amount - 1` arguments, obtained by fixing argument `var`
+    /// to `value`. Arguments above `var` are shifted down by one.
+    ///
+    /// # Errors
+    /// Returns `BFError::VarOutOfBounds` if `var >= args_amount`,
+    /// `BFError::InvalidBit` if `value > 1`, or `BFError::NoArgs` if `args_amount == 1`.
+    pub fn restrict(&self, var: usize, value: u8) -> Result<Self> {
+        if var >= self.args_amount {
+            return Err(BFError::VarOutOfBounds {
+                given: var,
+                bounds: self.args_amount,
+            });
+        }
+
+        if value > 1 {
+            return Err(BFError::InvalidBit(value));
+        }
+
+        let mut bf = BF::zero(self.args_amount - 1)?;
+        let low_mask = pow2(var) - 1;
+        for args in 0..pow2(self.args_amount - 1) {
+            let full = (args & low_mask) | ((args & !low_mask) << 1) | (value as usize) << var;
+            if self.eval(full) == 1 {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Returns function `g(x) = f(y)`, where bit `perm[i]` of `y` is bit `i` of `x`.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidPermutation` if `perm` is not a permutation of `0..args_amount`.
+    pub fn permute_vars(&self, perm: &[usize]) -> Result<Self> {
+        if !is_permutation(perm, self.args_amount) {
+            return Err(BFError::InvalidPermutation(perm.to_vec()));
+        }
+
+        let mut bf = BF::zero(self.args_amount)?;
+        for args in 0..pow2(self.args_amount) {
+            let permuted = (0..self.args_amount)
+                .filter(|&i| (args >> i) & 1 == 1)
+                .fold(0, |acc, i| acc | pow2(perm[i]));
+
+            if self.eval(permuted) == 1 {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Calculates distance between a function and its Reed-Muller expansion
+    /// truncated to degree `max_deg` (see `truncate_degree`).
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `max_deg > args_amount`.
+    pub fn rm_approximation_error(&self, max_deg: usize) -> Result<usize> {
+        self.hamming_distance(&self.truncate_degree(max_deg)?)
+    }
+
+    /// Returns function `g(x) = f(x ^ mask)`, i.e. function with arguments,
+    /// set in `mask`, negated. Equals `compose_affine` with identity matrix and shift `mask`.
+    ///
+    /// # Errors
+    /// Returns `BFError::ArgOutOfBounds` if `mask >= 2^args_amount`.
+    pub fn negate_vars(&self, mask: usize) -> Result<Self> {
+        if mask >= pow2(self.args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: mask,
+                bounds: pow2(self.args_amount),
+            });
+        }
+
+        // high bits of mask swap whole factors
+        let mut values: Vec<Value> = (0..self.values.len())
+            .map(|i| self.values[i ^ div_ws(mask)])
+            .collect();
+
+        // low bits of mask swap halves of blocks inside factors
+        for i in (0..log2(WORD_BIT_SIZE)).filter(|&i| (mask >> i) & 1 == 1) {
+            let shift = pow2(i);
+            let upper = halving_mask(i);
+            for value in values.iter_mut() {
+                *value = ((*value & upper) >> shift) | ((*value << shift) & upper);
+            }
+        }
+
+        Ok(BF {
+            values,
+            args_amount: self.args_amount,
+        })
+    }
+
+    /// Returns direct sum of functions on disjoint arguments: `h(x, y) = f(x) ^ g(y)`,
+    /// where `x` is formed by the least significant `self.args_amount` bits of argument.
+    ///
+    /// # Errors
+    /// Returns `BFError::TooManyArgs` if total amount of arguments is greater than `MAX_ARGS`.
+    pub fn direct_sum(&self, other: &BF) -> Result<Self> {
+        let n = self.args_amount;
+        let args_amount = n + other.args_amount;
+        if args_amount > MAX_ARGS {
+            return Err(BFError::TooManyArgs {
+                args_amount,
+                max: MAX_ARGS,
+            });
+        }
+
+        let mut bf = BF::zero(args_amount)?;
+        let low_mask = pow2(n) - 1;
+        for args in 0..pow2(args_amount) {
+            if self.eval(args & low_mask) != other.eval(args >> n) {
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Checks if function has no nonzero linear structures.
+    pub fn has_no_linear_structures(&self) -> bool {
+        self.linear_structures().is_empty()
+    }
+
+    /// Returns function of `args_amount + 1` arguments, which truth table is concatenation
+    /// of truth tables of functions: the most significant argument chooses `other`.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments,
+    /// `BFError::TooManyArgs` if resulting amount of arguments is greater than `MAX_ARGS`.
+    pub fn concatenate(&self, other: &BF) -> Result<Self> {
+        if self.args_amount != other.args_amount {
+            return Err(BFError::DimMismatch {
+                expected: self.args_amount,
+                given: other.args_amount,
+            });
+        }
+
+        check_args_amount(self.args_amount + 1)?;
+
+        let half_bits = pow2(self.args_amount);
+        let values = if half_bits >= WORD_BIT_SIZE {
+            [self.values.as_slice(), other.values.as_slice()].concat()
+        } else {
+            vec![self.values[0] | (other.values[0] << half_bits)]
+        };
+
+        Ok(BF {
+            values,
+            args_amount: self.args_amount + 1,
+        })
+    }
+
+    /// Calculates xor convolution of truth tables of functions as integer `0/1` vectors
+    /// for all shifts `z`: `h(z) = sum f(x) * g(x ^ z)`.
+    ///
+    /// Convolution is computed as inverse walsh adamar transform of pointwise product
+    /// of transformed truth tables.
+    ///
+    /// # Errors
+    /// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+    pub fn xor_convolution(&self, other: &BF) -> Result<Vec<i64>> {
+        if self.args_amount != other.args_amount {
+            return Err(BFError::DimMismatch {
+                expected: self.args_amount,
+                given: other.args_amount,
+            });
+        }
+
+        let transformed = |bf: &BF| -> Vec<i64> {
+            let mut table: Vec<i64> = (0..pow2(bf.args_amount))
+                .map(|args| bf.eval(args) as i64)
+                .collect();
+            fast_hadamard_transform(&mut table);
+            table
+        };
+
+        Ok(inverse_walsh_of_product(
+            &transformed(self),
+            &transformed(other),
+            self.args_amount,
+        ))
+    }
+
+    /// Returns walsh adamar coefficients of affine function `<mask, x> ^ constant`
+    /// without building it: the only nonzero coefficient is `W(mask) = ±2^n`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `args_amount == 0`,
+    /// `BFError::TooManyArgs` if `args_amount > MAX_ARGS`,
+    /// `BFError::ArgOutOfBounds` if `mask >= 2^args_amount`,
+    /// `BFError::InvalidBit` if `constant > 1`.
+    pub fn affine_walsh(args_amount: usize, mask: usize, constant: u8) -> Result<Vec<i64>> {
+        check_args_amount(args_amount)?;
+
+        if mask >= pow2(args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: mask,
+                bounds: pow2(args_amount),
+            });
+        }
+
+        if constant > 1 {
+            return Err(BFError::InvalidBit(constant));
+        }
+
+        let mut wac = vec![0; pow2(args_amount)];
+        wac[mask] = match constant {
+            0 => pow2(args_amount) as i64,
+            _ => -(pow2(args_amount) as i64),
+        };
+
+        Ok(wac)
+    }
+
+    /// Returns iterator over arguments, on which function equals `1`, in ascending order.
+    pub fn support(&self) -> impl Iterator<Item = usize> + '_ {
+        // NOTE: function assumes that unused bits in value set to zero.
+        self.values.iter().enumerate().flat_map(|(i, &value)| {
+            let mut rest = value;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+
+                let lowest = rest & rest.wrapping_neg();
+                rest ^= lowest;
+                Some(i * WORD_BIT_SIZE + lowest.trailing_zeros() as usize)
+            })
+        })
+    }
+
+    /// Returns iterator over values of a function on arguments `0, 1, ..., 2^args_amount - 1`.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..pow2(self.args_amount)).map(|args| self.eval(args) == 1)
+    }
+
+    /// Returns all subcubes of dimension `dim`, on which function equals `1`,
+    /// as pairs `(fixed, free)`: cube consists of arguments `fixed | sub`,
+    /// where `sub` is a submask of `free`, and `fixed & free == 0`.
+    /// Cubes are ordered by `free` as in `BinComb`, then by `fixed`.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `dim > args_amount`.
+    pub fn implicants(&self, dim: usize) -> Result<Vec<(usize, usize)>> {
+        if dim > self.args_amount {
+            return Err(BFError::InvalidDeg(dim));
+        }
+
+        let is_implicant = |fixed: usize, free: usize| -> bool {
+            let mut sub = free;
+            loop {
+                if self.eval(fixed | sub) == 0 {
+                    return false;
+                }
+                if sub == 0 {
+                    return true;
+                }
+                sub = (sub - 1) & free;
+            }
+        };
+
+        let implicants = BinComb::new(self.args_amount, dim)
+            .flat_map(|free| {
+                self.support()
+                    .filter(move |&fixed| fixed & free == 0)
+                    .filter(move |&fixed| is_implicant(fixed, free))
+                    .map(move |fixed| (fixed, free))
+            })
+            .collect();
+
+        Ok(implicants)
+    }
+
+    /// Returns truth table of a function in hexadecimal: `i`-th digit holds values
+    /// on arguments `4i..4i + 3`, the least significant bit being value on `4i`.
+    pub fn to_hex(&self) -> String {
+        (0..pow2(self.args_amount).div_ceil(4))
+            .map(|digit| {
+                let nibble = (0..4)
+                    .map(|bit| 4 * digit + bit)
+                    .filter(|&args| args < pow2(self.args_amount) && self.eval(args) == 1)
+                    .fold(0, |acc, args| acc | 1 << (args % 4));
+                char::from_digit(nibble, 16).expect("nibble is less than 16")
+            })
+            .collect()
+    }
+
+    /// Creates function of `args_amount` arguments from hexadecimal truth table
+    /// in format of `to_hex`. Both lower and upper case digits are accepted.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if `args_amount == 0`,
+    /// `BFError::InvalidLength` if length of `s` is not `ceil(2^args_amount / 4)`,
+    /// `BFError::InvalidString` if `s` contains non hexadecimal digits,
+    /// `BFError::UnusedBitsSet` if digits have bits outside of truth table.
+    pub fn from_hex(s: &str, args_amount: usize) -> Result<Self> {
+        let mut bf = BF::zero(args_amount)?;
+
+        let size = pow2(args_amount);
+        let expected = size.div_ceil(4);
+        if s.chars().count() != expected {
+            return Err(BFError::InvalidLength {
+                given: s.chars().count(),
+                expected,
+            });
+        }
+
+        for (digit, c) in s.chars().enumerate() {
+            let nibble = c
+                .to_digit(16)
+                .ok_or_else(|| BFError::InvalidString(s.to_string()))?;
+
+            for bit in (0..4).filter(|&bit| (nibble >> bit) & 1 == 1) {
+                let args = 4 * digit + bit;
+                if args >= size {
+                    return Err(BFError::UnusedBitsSet);
+                }
+                bf.set(args)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Calculates amount of sign changes between adjacent walsh adamar coefficients,
+    /// when masks are taken in Gray code order. Zero coefficients don't change sign.
+    pub fn walsh_sign_changes(&self) -> usize {
+        let wac = self.walsh_adamar();
+
+        (0..wac.len())
+            .map(|i| wac[gray(i)].signum())
+            .tuple_windows()
+            .filter(|(a, b)| a * b < 0)
+            .count()
+    }
+
+    /// Returns function in binary format, which doesn't depend on size of `Value`:
+    /// byte with `args_amount`, then `ceil(2^args_amount / 8)` bytes of truth table,
+    /// where bit `j` of byte `i` is value on argument `8i + j`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let size = pow2(self.args_amount);
+
+        let mut bytes = vec![0u8; 1 + size.div_ceil(8)];
+        bytes[0] = self.args_amount as u8;
+        for args in self.support() {
+            bytes[1 + args / 8] |= 1 << (args % 8);
+        }
+
+        bytes
+    }
+
+    /// Creates function from binary format of `to_bytes`.
+    ///
+    /// # Errors
+    /// Returns `BFError::NoArgs` if amount of arguments is zero,
+    /// `BFError::TooManyArgs` if it's greater than `MAX_ARGS`,
+    /// `BFError::InvalidLength` if amount of bytes doesn't match it,
+    /// `BFError::UnusedBitsSet` if bits outside of truth table are set.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let Some((&args_amount, table)) = bytes.split_first() else {
+            return Err(BFError::InvalidLength {
+                given: 0,
+                expected: 1,
+            });
+        };
+
+        // length is checked before allocating, so short input can't request a huge table
+        let args_amount = args_amount as usize;
+        check_args_amount(args_amount)?;
+
+        let size = pow2(args_amount);
+        if table.len() != size.div_ceil(8) {
+            return Err(BFError::InvalidLength {
+                given: bytes.len(),
+                expected: 1 + size.div_ceil(8),
+            });
+        }
+
+        let mut bf = BF::zero(args_amount)?;
+
+        if size < 8 && table[0] >> size != 0 {
+            return Err(BFError::UnusedBitsSet);
+        }
+
+        for (i, &byte) in table.iter().enumerate() {
+            for j in (0..8).filter(|&j| (byte >> j) & 1 == 1) {
+                bf.set(8 * i + j)?;
+            }
+        }
+
+        Ok(bf)
+    }
+
+    /// Converts string to boolean function in big-endian fashion:
+    /// last char in string corresponds to arguments with values zero.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidString` if `s` doesn't consist of zeros and ones,
+    /// or `BFError::NotPowTwo` if `len(s)` is not a power of 2.
+    pub fn from_str_be(s: &str) -> Result<Self> {
+        let reversed: String = s.chars().rev().collect();
+
+        BF::from_str(&reversed).map_err(|err| match err {
+            BFError::InvalidString(_) => BFError::InvalidString(s.to_string()),
+            err => err,
+        })
+    }
+
+    /// Returns matrix, which rows are arguments from support of a function
+    /// in ascending order. Column `j` holds bit `j` of arguments.
+    ///
+    /// # Errors
+    /// Returns `BFError::ZeroFunction` if function equals `0` for all arguments.
+    pub fn support_matrix(&self) -> Result<BM> {
+        let mut bm =
+            BM::zero(self.weight(), self.args_amount).map_err(|_| BFError::ZeroFunction)?;
+        for (row, args) in self.support().enumerate() {
+            for col in (0..self.args_amount).filter(|&col| (args >> col) & 1 == 1) {
+                bm.set(row, col);
+            }
+        }
+
+        Ok(bm)
+    }
+
+    /// Returns coefficients of the weight enumerator of a function support viewed as a code:
+    /// `i`-th element is the number of arguments of Hamming weight `i` on which function equals `1`.
+    ///
+    /// Vector has length `args_amount + 1` and its sum equals `weight()`.
+    pub fn support_weight_enumerator(&self) -> Vec<usize> {
+        let mut enumerator = vec![0; self.args_amount + 1];
+        for args in self.support() {
+            enumerator[args.count_ones() as usize] += 1;
+        }
+
+        enumerator
+    }
+
+    /// Same as `walsh_adamar`, but butterfly stages of the transform are processed by rayon thread pool.
+    #[cfg(feature = "rayon")]
+    pub fn walsh_adamar_parallel(&self) -> Vec<i64> {
+        use rayon::prelude::*;
+
+        let mut char_vec = (0..pow2(self.args_amount))
+            .into_par_iter()
+            .map(|arg| match self.eval(arg) {
+                0 => 1,
+                1 => -1,
+                _ => panic!("function evaluated to non binary"),
+            })
+            .collect::<Vec<i64>>();
+
+        fast_hadamard_transform_parallel(&mut char_vec);
+
+        char_vec
+    }
+
+    /// Calculates autocorrelation of a function at single shift `a`:
+    /// `C(a) = sum (-1)^(f(x) ^ f(x ^ a))` in `O(2^n)` without building the whole spectrum.
+    ///
+    /// Equals `2^n - 2 * derivative(a).weight()`.
+    ///
+    /// # Errors
+    /// Returns `BFError::ArgOutOfBounds` if `a >= 2^args_amount`.
+    pub fn autocor_at(&self, a: usize) -> Result<i64> {
+        if a >= pow2(self.args_amount) {
+            return Err(BFError::ArgOutOfBounds {
+                given: a,
+                bounds: pow2(self.args_amount),
+            });
+        }
+
+        let differ = (0..pow2(self.args_amount))
+            .filter(|&args| self.eval(args) != self.eval(args ^ a))
+            .count();
+
+        Ok(pow2(self.args_amount) as i64 - 2 * differ as i64)
+    }
+
+    /// Returns covering radius bound on nonlinearity of a function with `args_amount` arguments:
+    /// `floor(2^(n-1) - 2^(n/2-1))`. It's reached by bent functions for even `n`,
+    /// for odd `n` the bound is not tight.
+    pub fn nonlinearity_upper_bound(&self) -> usize {
+        let half_root = ((pow2(self.args_amount) as f64).sqrt() / 2.0).ceil() as usize;
+        pow2(self.args_amount - 1) - half_root
+    }
+
+    /// Calculates how far function nonlinearity is from the optimal:
+    /// `nonlinearity_upper_bound() - nonlinearity()`. For even `n` it's `0` only for bent functions.
+    ///
+    /// For odd `n` bent functions don't exist and the same covering radius bound is used,
+    /// so defect of the best functions is usually positive.
+    pub fn bentness_defect(&self) -> usize {
+        self.nonlinearity_upper_bound() - self.nonlinearity()
+    }
+
+    /// Returns monomials of degree `deg` present in arithmetic normal form of a function.
+    /// Monomials are given as in `maximal_monomials` (e.g. `[2, 1]` for `x2&x1`).
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidDeg` if `deg > args_amount`.
+    pub fn monomials_of_degree(&self, deg: usize) -> Result<Vec<Vec<usize>>> {
+        let masks = self.monomial_masks(deg)?;
+
+        let mut bf_copy = self.clone();
+        let bf_mob = bf_copy.mobius();
+
+        Ok(masks
+            .filter(|&mask| bf_mob.eval(mask) == 1)
+            .map(|mask| self.monomial_vars(mask))
+            .collect())
+    }
+
+    /// Returns value of argument `var`, fixing which gives subfunction (see `restrict`)
+    /// of the higher nonlinearity, and that nonlinearity. On ties `0` is returned.
+    ///
+    /// # Errors
+    /// Same as `restrict`.
+    pub fn best_restriction(&self, var: usize) -> Result<(u8, usize)> {
+        let nl0 = self.restrict(var, 0)?.nonlinearity();
+        let nl1 = self.restrict(var, 1)?.nonlinearity();
+
+        match nl1 > nl0 {
+            true => Ok((1, nl1)),
+            false => Ok((0, nl0)),
+        }
+    }
+}
+
+impl FromStr for BF {
+    type Err = BFError;
+
+    /// Converts string to boolean function
+    /// First char in string correspond to arguments with values zero.
+    /// Last char in string to arguments with values one.
+    ///
+    /// # Errors
+    /// Returns `BFError::InvalidString` if `s` doesn't consist of zeros and ones,
+    /// or `BFError::NotPowTwo` if `len(s)` is not a power of 2.
+    fn from_str(s: &str) -> Result<Self> {
+        let len = s.len();
+        if len == 1 || !is_pow2(len) {
+            return Err(BFError::NotPowTwo(len));
+        }
+
+        let mut bf = BF::zero(log2(len)).expect("length not zero");
+
+        for (i, bit) in s.chars().enumerate() {
+            match bit {
+                '0' => (), // value already zero
+                '1' => bf.set(i)?,
+                _ => return Err(BFError::InvalidString(s.to_string())),
+            };
+        }
+
+        Ok(bf)
+    }
+}
+
+impl fmt::Display for BF {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string: String = self
+            .iter()
+            .map(|value| if value { '1' } else { '0' })
+            .collect();
+
+        write!(f, "{}", string)
+    }
+}
+
+impl BitXor for BF {
+    type Output = BF;
+
+    /// Returns sum of functions over GF(2).
+    ///
+    /// # Panics
+    /// Panics if functions have different amount of arguments.
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.xor(&rhs)
+            .expect("functions have the same amount of arguments")
+    }
+}
+
+impl BitAnd for BF {
+    type Output = BF;
+
+    /// Returns pointwise conjunction of functions.
+    ///
+    /// # Panics
+    /// Panics if functions have different amount of arguments.
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.try_and(&rhs)
+            .expect("functions have the same amount of arguments")
+    }
+}
+
+impl BitOr for BF {
+    type Output = BF;
+
+    /// Returns pointwise disjunction of functions.
+    ///
+    /// # Panics
+    /// Panics if functions have different amount of arguments.
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.try_or(&rhs)
+            .expect("functions have the same amount of arguments")
+    }
+}
+
+impl Not for BF {
+    type Output = BF;
+
+    /// Returns complement of a function.
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+/// Calculates maximal absolute cross correlation over all ordered pairs
+/// of different functions from `funcs` and all nonzero shifts.
+///
+/// # Errors
+/// Returns `BFError::DimMismatch` if functions have different amount of arguments.
+pub fn cross_correlation_max(funcs: &[BF]) -> Result<i64> {
+    let mut max = 0;
+    for (i, f) in funcs.iter().enumerate() {
+        for (j, g) in funcs.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+
+            let ccv = f.cross_correlation(g)?;
+            if let Some(m) = ccv.iter().skip(1).map(|v| v.abs()).max() {
+                max = max.max(m);
+            }
+        }
+    }
+
+    Ok(max)
+}
+
+/// Calculates `2^(-args_amount) * H(a * b)`, where `a * b` is pointwise product
+/// of walsh adamar spectra and `H` is walsh adamar transform.
+///
+/// Products reach `2^(2n)` and their sums `2^(3n)`, so they are accumulated in `i128`.
+fn inverse_walsh_of_product(a: &[i64], b: &[i64], args_amount: usize) -> Vec<i64> {
+    let mut product: Vec<i128> = a
+        .iter()
+        .zip(b)
+        .map(|(&a, &b)| a as i128 * b as i128)
+        .collect();
+
+    fast_hadamard_transform(&mut product);
+
+    product
+        .into_iter()
+        .map(|v| (v >> args_amount) as i64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::manual_div_ceil)]
+    fn zero_works() {
+        let args_amount = 4;
+        let bf = BF::zero(args_amount).expect("args_amount is not zero");
+        for value in &bf.values {
+            assert!(*value == 0);
+        }
+
+        // expected_length holds ceil((2^args_amount)/WORD_BIT_SIZE)
+        let expected_length = (pow2(args_amount) + WORD_BIT_SIZE - 1) / WORD_BIT_SIZE;
+        assert!(bf.values.len() == expected_length);
+        assert!(bf.args_amount == args_amount);
+    }
+
+    #[test]
+    fn too_many_args_is_rejected() {
+        let err = Err(BFError::TooManyArgs {
+            args_amount: MAX_ARGS + 1,
+            max: MAX_ARGS,
+        });
+        assert_eq!(BF::zero(MAX_ARGS + 1), err);
+        assert_eq!(BF::one(MAX_ARGS + 1), err);
+        assert_eq!(BF::random(MAX_ARGS + 1), err);
+        assert_eq!(BF::linear(MAX_ARGS + 1, 1), err);
+
+        for args_amount in [40, 64, usize::MAX] {
+            assert_eq!(
+                BF::random(args_amount),
+                Err(BFError::TooManyArgs {
+                    args_amount,
+                    max: MAX_ARGS
+                })
+            );
+        }
+
+        assert_eq!(BF::zero(0), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    #[allow(clippy::manual_div_ceil)]
+    fn one_works() {
+        let args_amount = WORD_SIZE;
+        let bf = BF::one(args_amount).expect("args_amount is not zero");
+
+        for value in &bf.values[..bf.values.len() - 1] {
+            assert!(*value == Value::MAX);
+        }
+
+        // expected_length holds ceil((2^args_amount)/WORD_BIT_SIZE)
+        let expected_length = (pow2(args_amount) + WORD_BIT_SIZE - 1) / WORD_BIT_SIZE;
+        assert!(bf.values.len() == expected_length);
+        assert!(bf.args_amount == args_amount);
+
+        assert!(bf.weight() == pow2(args_amount))
+    }
+
+    #[test]
+    fn weight_works() {
+        let args_amount = 2;
+        let bf = BF::one(args_amount).expect("args_amount is not zero");
+        assert!(bf.weight() == 4);
+
+        let args_amount = log2(WORD_BIT_SIZE);
+        let bf = BF::one(args_amount).expect("args_amount is not zero");
+        assert!(bf.weight() == WORD_BIT_SIZE);
+    }
+
+    #[test]
+    fn str_works() {
+        fn test_valid(s: &str) {
+            let str_before = String::from(s);
+            let bf_before = str_before.parse::<BF>().expect("Can parse string");
+            let str_after = bf_before.to_string();
+            let bf_after = str_after.parse::<BF>().expect("Can parse string");
+            assert!(str_before == str_after);
+            assert!(bf_before == bf_after);
+        }
+
+        test_valid("1111");
+        test_valid("0001");
+        test_valid("10111101");
+        test_valid("1011011101110101");
+        test_valid("10000000000000000000001000000000");
+
+        fn test_not_boolen(s: &str) {
+            let res = s.parse::<BF>();
+            match res {
+                Ok(_) => panic!("Should return error"),
+                Err(err) => assert_eq!(err, BFError::InvalidString(s.to_string())),
+            }
+        }
+
+        test_not_boolen("20");
+        test_not_boolen("3333");
+        test_not_boolen("111s");
+
+        fn test_not_pow_two(s: &str) {
+            let res = s.parse::<BF>();
+            match res {
+                Ok(_) => panic!("Should return error"),
+                Err(err) => assert_eq!(err, BFError::NotPowTwo(s.len())),
+            }
+        }
+
+        test_not_pow_two("0");
+        test_not_pow_two("111");
+        test_not_pow_two("11111111111111111111111111111");
+    }
+
+    #[test]
+    fn eval_works() {
+        let bf = BF::from_str("1010110011110000").expect("Can convert");
+        // TODO: iterate over string
+        assert_eq!(bf.eval(0), 1);
+        assert_eq!(bf.eval(1), 0);
+        assert_eq!(bf.eval(2), 1);
+        assert_eq!(bf.eval(8), 1);
+        assert_eq!(bf.eval(9), 1);
+        assert_eq!(bf.eval(14), 0);
+        assert_eq!(bf.eval(15), 0);
+    }
+
+    #[test]
+    fn set_works() {
+        fn test_set_unset(bf: &mut BF, args: usize) {
+            assert_eq!(bf.eval(args), 0);
+            bf.set(args).expect("Valid arg");
+            assert_eq!(bf.eval(args), 1);
+            bf.unset(args).expect("Valid arg");
+            assert_eq!(bf.eval(args), 0);
+        }
+
+        let mut bf = BF::zero(log2(WORD_BIT_SIZE) + 1).expect("Args amount not zero");
+        for i in 0..pow2(bf.args_amount) {
+            test_set_unset(&mut bf, i);
+        }
+    }
+
+    #[test]
+    fn mobius_random_reversability() {
+        for i in 0..100 {
+            let mut bf = BF::random(i % 16 + 1).expect("arg amount is not zero");
+            let old = bf.clone();
+            bf.mobius();
+            bf.mobius();
+            println!("{}", i % 16 + 1);
+            assert!(bf == old);
+        }
+    }
+
+    #[test]
+    fn mobius_31_factor_reversability() {
+        let mut bf = BF::random(31).expect("arg amount is not zero");
+        let old = bf.clone();
+        bf.mobius();
+        bf.mobius();
+        assert!(bf == old);
+    }
+
+    #[test]
+    fn mobius_transform_const0_anf() {
+        let mut bf = BF::zero(16).unwrap();
+        let anf = bf.anf();
+
+        bf.mobius();
+        assert_eq!(bf.to_string(), "0".repeat(pow2(16) as usize));
+        assert_eq!(anf, "0");
+    }
+
+    #[test]
+    fn mobius_transform_const1_anf() {
+        let mut bf = BF::one(16).unwrap();
+        let anf = bf.anf();
+
+        bf.mobius();
+        assert_eq!(bf.to_string(), "1".to_owned() + &"0".repeat(pow2(16) - 1));
+        assert_eq!(anf, "1");
+    }
+
+    #[test]
+    fn anf_works() {
+        let bf = BF::from_str("0001000100011110000100010001111000010001000111101110111011100001")
+            .expect("can convert");
+        assert_eq!(bf.anf(), "x6&x5 + x4&x3 + x2&x1");
+        assert_eq!(bf.deg(), 2);
+
+        let mut bf = BF::from_str("11000110").expect("can convert");
+        bf.mobius();
+        assert_eq!(bf.anf(), "1 + x3 + x3&x1 + x2&x1");
+
+        let mut bf = BF::from_str("1111").expect("can convert");
+        bf.mobius();
+        assert_eq!(bf.anf(), "1 + x2 + x1 + x2&x1");
+
+        let mut bf = BF::from_str("0000").expect("can convert");
+        bf.mobius();
+        assert_eq!(bf.anf(), "0");
+    }
+
+    #[test]
+    fn degree_works() {
+        let bf = BF::one(16).unwrap();
+        assert_eq!(bf.deg(), 0);
+
+        let bf = BF::zero(16).unwrap();
+        assert_eq!(bf.deg(), 0);
+
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.deg(), 2);
+
+        let bf = BF::from_str("00000001").unwrap();
+        assert_eq!(bf.deg(), 3);
+
+        let bf = "1".to_owned() + &"0".repeat(pow2(16) - 1);
+        let bf = BF::from_str(&bf).unwrap();
+        assert_eq!(bf.deg(), 16);
+    }
+
+    #[test]
+    fn walsh_adamar_works() {
+        let bf = BF::from_str("0110").unwrap();
+        let wac = bf.walsh_adamar();
+        assert_eq!(wac, vec![0, 0, 0, 4]);
+
+        let bf = BF::from_str("0001000100011110").unwrap();
+        let wac = bf.walsh_adamar();
+        assert_eq!(
+            wac,
+            vec![4, 4, 4, -4, 4, 4, 4, -4, 4, 4, 4, -4, -4, -4, -4, 4]
+        );
+
+        for i in 1..=3 {
+            let bf = BF::one(i * 3).unwrap();
+            let wac = bf.walsh_adamar();
+            let mut expected = vec![0i64; pow2(i * 3)];
+            expected[0] = -(pow2(i * 3) as i64);
+            assert_eq!(wac, expected);
+        }
+    }
+
+    #[test]
+    fn inverse_walsh_of_product_doesnt_overflow() {
+        // spectrum of constant one of 32 arguments folded to 4 coefficients
+        let wac = [-(1i64 << 32), 0, 0, 0];
+        assert_eq!(
+            inverse_walsh_of_product(&wac, &wac, 32),
+            vec![1i64 << 32; 4]
+        );
+
+        let other = [1i64 << 32, 0, 0, 0];
+        assert_eq!(
+            inverse_walsh_of_product(&wac, &other, 32),
+            vec![-(1i64 << 32); 4]
+        );
+    }
+
+    #[test]
+    fn xor_convolution_of_widest_functions_doesnt_overflow() {
+        // transformed 0/1 truth table of constant one folded to 4 coefficients,
+        // self convolution equals 2^n for all shifts
+        for n in [31, 32] {
+            let transformed = [1i64 << n, 0, 0, 0];
+            assert_eq!(
+                inverse_walsh_of_product(&transformed, &transformed, n),
+                vec![1i64 << n; 4]
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "needs about 32 GiB of memory"]
+    fn walsh_adamar_doesnt_overflow() {
+        let wac = BF::one(32).unwrap().walsh_adamar();
+        assert_eq!(wac[0], -(1i64 << 32));
+        assert!(wac.iter().skip(1).all(|&coef| coef == 0));
+    }
+
+    #[test]
+    fn cor_works() {
+        let args_amount = 16;
+
+        let bf = BF::one(args_amount).unwrap();
+        assert_eq!(bf.cor(), args_amount);
+
+        let bf = BF::zero(args_amount).unwrap();
+        assert_eq!(bf.cor(), args_amount);
+
+        let bf = BF::from_str("01101001").unwrap();
+        assert_eq!(bf.cor(), 2);
+    }
+
+    #[test]
+    fn nonlinearity_works() {
+        let bf = BF::from_str("01111010").unwrap();
+        assert_eq!(bf.nonlinearity(), 1);
+
+        let bf = BF::from_str("01100000").unwrap();
+        assert_eq!(bf.nonlinearity(), 2);
+
+        let bf = BF::from_str("00000000").unwrap();
+        assert_eq!(bf.nonlinearity(), 0);
+
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.nonlinearity(), 1);
+
+        for n in 1..8 {
+            for coefs in 0..pow2(n) {
+                let bf = BF::linear(n, coefs).unwrap();
+                assert_eq!(bf.nonlinearity(), 0);
+                assert_eq!(bf.inverse().nonlinearity(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn linear_works() {
+        let bf = BF::linear(3, 0b101).unwrap();
+        assert_eq!(bf.to_string(), "01011010");
+        assert_eq!(bf, BF::from_anf("x1 + x3", 3).unwrap());
+
+        for n in 1..8 {
+            for mask in 0..pow2(n) {
+                let wac = BF::linear(n, mask).unwrap().walsh_adamar();
+                for (i, &coef) in wac.iter().enumerate() {
+                    let expected = if i == mask { pow2(n) as i64 } else { 0 };
+                    assert_eq!(coef, expected);
+                }
+            }
+        }
+
+        assert_eq!(
+            BF::linear(3, 8),
+            Err(BFError::ArgOutOfBounds {
+                given: 8,
+                bounds: 8
+            })
+        );
+    }
+
+    #[test]
+    fn affine_works() {
+        assert_eq!(BF::affine(3, 0b101, 1).unwrap().to_string(), "10100101");
+        assert_eq!(BF::affine(2, 0, 1).unwrap(), BF::one(2).unwrap());
+
+        for n in 1..7 {
+            for mask in 0..pow2(n) {
+                let bf = BF::affine(n, mask, 1).unwrap();
+                assert_eq!(bf.nonlinearity(), 0);
+                assert_eq!(bf.walsh_adamar()[mask], -(pow2(n) as i64));
+                assert_eq!(bf, BF::linear(n, mask).unwrap().inverse());
+            }
+        }
+
+        assert_eq!(BF::affine(3, 1, 2), Err(BFError::InvalidBit(2)));
+        assert_eq!(
+            BF::affine(3, 9, 0),
+            Err(BFError::ArgOutOfBounds {
+                given: 9,
+                bounds: 8
+            })
+        );
+    }
+
+    #[test]
+    fn inverse_works() {
+        let bf = BF::from_str("1100").unwrap();
+        let inv = bf.inverse();
+        assert_eq!(inv.to_string(), "0011");
+    }
+
+    #[test]
+    fn best_affine_approx_works() {
+        let bf = BF::from_str("01111010").unwrap();
+        let approx = bf.best_affine_approx();
+        assert_eq!(approx.to_string(), "01011010");
+
+        let bf = BF::zero(3).unwrap();
+        let approx = bf.best_affine_approx();
+        assert_eq!(approx.to_string(), "00000000");
+
+        let bf = BF::one(3).unwrap();
+        let approx = bf.best_affine_approx();
+        assert_eq!(approx.to_string(), "11111111");
+
+        let bf = BF::from_str("01010101").unwrap();
+        let approx = bf.best_affine_approx();
+        assert_eq!(approx.to_string(), "01010101");
+
+        let bf = BF::from_str("01100000").unwrap();
+        let approx = bf.best_affine_approx();
+        assert_eq!(approx.to_string(), "00000000");
+    }
+
+    #[test]
+    fn autocor_works() {
+        let bf = BF::from_str("0001").unwrap();
+        let acv = bf.autocor();
+        assert_eq!(acv, &[4, 0, 0, 0]);
+
+        for n in 1..12 {
+            let acv = BF::one(n).unwrap().autocor();
+            assert!(acv.iter().all(|&v| v as usize == pow2(n)));
+        }
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let acv = bf.autocor();
+            assert_eq!(acv[0] as usize, pow2(n));
+
+            for (a, &v) in acv.iter().enumerate() {
+                let naive: i64 = (0..pow2(n))
+                    .map(|x| 1 - 2 * (bf.eval(x) ^ bf.eval(x ^ a)) as i64)
+                    .sum();
+                assert_eq!(v, naive);
+            }
+        }
+    }
+
+    #[test]
+    fn pc_cor_works() {
+        let bf = BF::from_str("0001").unwrap();
+        let pcd = bf.pc_deg();
+        assert_eq!(pcd, 2);
+    }
+
+    #[test]
+    fn comp_nonlinearity_works() {
+        let bf = BF::from_str("0001000100011110").unwrap();
+        let cn = bf.comp_nonlinearity();
+        println!("CNf = {cn}");
+
+        let bf = BF::from_str("0001000100011110000100010001111000010001000111101110111011100001")
+            .unwrap();
+        let cn = bf.comp_nonlinearity();
+        println!("CNf = {cn}");
+    }
+
+    #[test]
+    fn obdd_size_works() {
+        // majority of 4 arguments is symmetric, so order doesn't matter
+        let mut bf = BF::zero(4).unwrap();
+        for args in 0..pow2(4) {
+            if weight(args) >= 2 {
+                bf.set(args).unwrap();
+            }
+        }
+
+        let size = bf.obdd_size(&[0, 1, 2, 3]).unwrap();
+        assert!(size <= 2 + 4 * 3);
+        assert_eq!(bf.obdd_size(&[3, 1, 0, 2]).unwrap(), size);
+        assert_eq!(bf.obdd_size(&[2, 3, 1, 0]).unwrap(), size);
+
+        // parity: two nodes on each level except root, plus terminals
+        let bf = BF::from_str("0110100110010110").unwrap();
+        assert_eq!(bf.obdd_size(&[0, 1, 2, 3]).unwrap(), 9);
+
+        let bf = BF::zero(3).unwrap();
+        assert_eq!(bf.obdd_size(&[1, 2, 0]).unwrap(), 1);
+
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.obdd_size(&[1, 0]).unwrap(), 4);
+
+        assert_eq!(
+            bf.obdd_size(&[0, 0]),
+            Err(BFError::InvalidPermutation(vec![0, 0]))
+        );
+        assert_eq!(
+            bf.obdd_size(&[0]),
+            Err(BFError::InvalidPermutation(vec![0]))
+        );
+    }
+
+    #[test]
+    fn best_obdd_order_works() {
+        // x1&x4 + x2&x5 + x3&x6 is sensitive to variable order
+        let mut bf = BF::zero(6).unwrap();
+        for args in 0..pow2(6) {
+            let value = (args & (args >> 3)).count_ones() & 1;
+            if value == 1 {
+                bf.set(args).unwrap();
+            }
+        }
+
+        let identity: Vec<usize> = (0..6).collect();
+        let identity_size = bf.obdd_size(&identity).unwrap();
+        let (order, size) = bf.best_obdd_order();
+        assert_eq!(bf.obdd_size(&order).unwrap(), size);
+        assert!(size < identity_size);
+        assert_eq!(size, 12);
+
+        for _ in 0..5 {
+            let bf = BF::random(7).unwrap();
+            let identity: Vec<usize> = (0..7).collect();
+            let (order, size) = bf.best_obdd_order();
+            assert_eq!(bf.obdd_size(&order).unwrap(), size);
+            assert!(size <= bf.obdd_size(&identity).unwrap());
+        }
+    }
+
+    #[test]
+    fn walsh_sign_function_works() {
+        // x1&x2 + x3&x4 is bent and self-dual
+        let bf = BF::from_str("0001000100011110").unwrap();
+        let dual = bf.walsh_sign_function();
+        assert_eq!(dual, bf);
+
+        // W_f(w) = 2^(n/2) * (-1)^dual(w) for bent function
+        let bf = BF::from_str("0001000100011110000100010001111000010001000111101110111011100001")
+            .unwrap();
+        let dual = bf.walsh_sign_function();
+        for (w, coef) in bf.walsh_adamar().iter().enumerate() {
+            let expected = if dual.eval(w) == 1 { -8 } else { 8 };
+            assert_eq!(*coef, expected);
+        }
+
+        let bf = BF::one(3).unwrap();
+        assert_eq!(bf.walsh_sign_function().to_string(), "10000000");
+    }
+
+    #[test]
+    fn nnf_works() {
+        // x1 xor x2 = x1 + x2 - 2 x1 x2
+        let bf = BF::from_str("0110").unwrap();
+        assert_eq!(bf.nnf(), vec![0, 1, 1, -2]);
+
+        let bf = BF::one(3).unwrap();
+        assert_eq!(bf.nnf(), vec![1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn numerical_degree_works() {
+        let bf = BF::from_str("0110").unwrap();
+        assert_eq!(bf.deg(), 1);
+        assert_eq!(bf.numerical_degree(), 2);
+
+        let bf = BF::zero(4).unwrap();
+        assert_eq!(bf.numerical_degree(), 0);
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            assert!(bf.numerical_degree() >= bf.deg());
+        }
+    }
+
+    #[test]
+    fn hamming_sphere_works() {
+        let bf = BF::hamming_sphere(3, 0, 1).unwrap();
+        assert_eq!(bf.to_string(), "01101000");
+
+        let bf = BF::hamming_sphere(3, 0b111, 0).unwrap();
+        assert_eq!(bf.to_string(), "00000001");
+
+        for radius in 0..=6 {
+            let bf = BF::hamming_sphere(6, 0b101100, radius).unwrap();
+            assert_eq!(bf.weight(), comb(6, radius));
+        }
+
+        assert_eq!(
+            BF::hamming_sphere(3, 8, 1),
+            Err(BFError::ArgOutOfBounds {
+                given: 8,
+                bounds: 8
+            })
+        );
+        assert_eq!(
+            BF::hamming_sphere(3, 0, 4),
+            Err(BFError::ArgOutOfBounds {
+                given: 4,
+                bounds: 4
+            })
+        );
+        assert_eq!(BF::hamming_sphere(0, 0, 0), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn support_is_subspace_works() {
+        // arguments with zero in first two coordinates
+        let bf = BF::from_str("1000100010001000").unwrap();
+        assert!(bf.support_is_subspace());
+
+        // {0, x1 ^ x2, x3, x1 ^ x2 ^ x3}
+        let bf = BF::from_str("1001000010010000").unwrap();
+        assert!(bf.support_is_subspace());
+
+        assert!(BF::one(4).unwrap().support_is_subspace());
+        assert!(BF::from_str("1000").unwrap().support_is_subspace());
+
+        assert!(!BF::zero(4).unwrap().support_is_subspace());
+        assert!(!BF::from_str("0110").unwrap().support_is_subspace());
+        assert!(!BF::from_str("1110").unwrap().support_is_subspace());
+        assert!(!BF::from_str("1001100000000000")
+            .unwrap()
+            .support_is_subspace());
+    }
+
+    #[test]
+    fn extended_walsh_signature_works() {
+        // x1&x2 + x3
+        let bf = BF::from_str("00011110").unwrap();
+        assert_eq!(bf.extended_walsh_signature(), vec![(0, 4, 2), (4, 4, 2)]);
+
+        // x2&x3 + x1 is permutation of arguments of a function above
+        let permuted = BF::from_str("01010110").unwrap();
+        assert_eq!(
+            bf.extended_walsh_signature(),
+            permuted.extended_walsh_signature()
+        );
+        assert_eq!(
+            bf.extended_walsh_signature(),
+            bf.inverse().extended_walsh_signature()
+        );
+
+        // x1&x2 + x1 is affine equivalent to x1&x2 + x3
+        let other = BF::from_str("00010100").unwrap();
+        assert_eq!(
+            bf.extended_walsh_signature(),
+            other.extended_walsh_signature()
+        );
+
+        let bf = BF::from_str("00100100010010000011101000110000").unwrap();
+        let signature = bf.extended_walsh_signature();
+        let mut matrix = BM::zero(5, 5).unwrap();
+        for (row, col) in [(0, 1), (1, 3), (2, 0), (3, 4), (4, 2), (0, 2), (3, 1)] {
+            matrix.set(row, col);
+        }
+        let transformed =
+            bf.compose_affine(&matrix, 0b10110).unwrap() ^ BF::affine(5, 0b01101, 1).unwrap();
+        assert_eq!(transformed.extended_walsh_signature(), signature);
+
+        // same walsh coefficients multiset, but different signature
+        let other = BF::from_str("00110100000001000110100010101000").unwrap();
+        assert_eq!(other.walsh_digest(), bf.walsh_digest());
+        assert_eq!(signature, vec![(4, 28, 5), (12, 4, 3)]);
+        assert_eq!(
+            other.extended_walsh_signature(),
+            vec![(4, 28, 5), (12, 4, 2)]
+        );
+    }
+
+    #[test]
+    fn flip_subcube_works() {
+        for args_amount in 1..=6 {
+            let mut bf = BF::random(args_amount).unwrap();
+            let inv = bf.inverse();
+            bf.flip_subcube(0, 0).unwrap();
+            assert_eq!(bf, inv);
+        }
+
+        let mut bf = BF::zero(3).unwrap();
+        bf.flip_subcube(0b101, 0b001).unwrap();
+        assert_eq!(bf.to_string(), "01010000");
+
+        let mut bf = BF::zero(5).unwrap();
+        bf.flip_subcube(0b10010, 0b10000).unwrap();
+        for args in 0..pow2(5) {
+            let expected = (args & 0b10010 == 0b10000) as u8;
+            assert_eq!(bf.eval(args), expected);
+        }
+
+        let mut bf = BF::zero(3).unwrap();
+        assert_eq!(
+            bf.flip_subcube(0b1000, 0),
+            Err(BFError::ArgOutOfBounds {
+                given: 0b1000,
+                bounds: 8
+            })
+        );
+        assert_eq!(
+            bf.flip_subcube(0b001, 0b010),
+            Err(BFError::ValuesOutsideMask {
+                mask: 0b001,
+                values: 0b010
+            })
+        );
+    }
+
+    #[test]
+    fn min_anf_weight_works() {
+        // x2&x1
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.anf_weight(), 1);
+        assert_eq!(bf.inverse().anf_weight(), 2);
+        assert_eq!(bf.min_anf_weight(), 1);
+        assert_eq!(bf.inverse().min_anf_weight(), 1);
+
+        let bf = BF::one(4).unwrap();
+        assert_eq!(bf.anf_weight(), 1);
+        assert_eq!(bf.min_anf_weight(), 0);
+    }
+
+    #[test]
+    fn sensitivity_works() {
+        let bf = BF::from_str("0110100110010110").unwrap();
+        for args in 0..pow2(4) {
+            assert_eq!(bf.sensitivity(args).unwrap(), 4);
+        }
+        assert_eq!(bf.max_sensitivity(), 4);
+
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.sensitivity(0).unwrap(), 0);
+        assert_eq!(bf.sensitivity(1).unwrap(), 1);
+        assert_eq!(bf.sensitivity(3).unwrap(), 2);
+        assert_eq!(bf.max_sensitivity(), 2);
+
+        assert_eq!(BF::zero(5).unwrap().max_sensitivity(), 0);
+        assert_eq!(
+            bf.sensitivity(4),
+            Err(BFError::ArgOutOfBounds {
+                given: 4,
+                bounds: 4
+            })
+        );
+    }
+
+    #[test]
+    fn average_sensitivity_works() {
+        let bf = BF::from_str("0110100110010110").unwrap();
+        assert_eq!(bf.average_sensitivity(), 4.0);
+
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.influence(0).unwrap(), 0.5);
+        assert_eq!(bf.influence(1).unwrap(), 0.5);
+        assert_eq!(bf.average_sensitivity(), 1.0);
+        assert_eq!(
+            bf.influence(2),
+            Err(BFError::VarOutOfBounds {
+                given: 2,
+                bounds: 2
+            })
+        );
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let sum: f64 = (0..bf.args_amount)
+                .map(|var| bf.influence(var).unwrap())
+                .sum();
+            assert!((bf.average_sensitivity() - sum).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn truncate_degree_works() {
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.truncate_degree(bf.args_amount).unwrap(), bf);
+            assert!(bf.truncate_degree(1).unwrap().deg() <= 1);
+            assert_eq!(
+                bf.truncate_degree(bf.args_amount + 1),
+                Err(BFError::InvalidDeg(bf.args_amount + 1))
+            );
+        }
+
+        let bf = BF::from_str("11000110").unwrap();
+        assert_eq!(bf.anf(), "1 + x2 + x1 + x3&x1");
+        assert_eq!(bf.truncate_degree(1).unwrap().anf(), "1 + x2 + x1");
+        assert_eq!(bf.truncate_degree(0).unwrap().anf(), "1");
+    }
+
+    #[test]
+    fn best_low_degree_approximation_works() {
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+
+            let (approx, distance) = bf.best_low_degree_approximation(1).unwrap();
+            assert_eq!(approx, bf.best_affine_approx());
+            assert_eq!(distance, bf.nonlinearity());
+
+            let (approx, distance) = bf.best_low_degree_approximation(0).unwrap();
+            assert_eq!(approx.deg(), 0);
+            assert_eq!(
+                distance,
+                bf.weight().min(pow2(bf.args_amount) - bf.weight())
+            );
+
+            let n = bf.args_amount;
+            let (approx, distance) = bf.best_low_degree_approximation(n).unwrap();
+            assert_eq!(approx, bf);
+            assert_eq!(distance, 0);
+        }
+
+        let bf = BF::from_str("0001000100011110").unwrap();
+        let (approx, distance) = bf.best_low_degree_approximation(2).unwrap();
+        assert_eq!(approx, bf);
+        assert_eq!(distance, 0);
+
+        assert_eq!(
+            bf.best_low_degree_approximation(5),
+            Err(BFError::InvalidDeg(5))
+        );
+    }
+
+    #[test]
+    fn monomial_masks_works() {
+        let bf = BF::zero(6).unwrap();
+        for deg in 0..=6 {
+            let masks: Vec<usize> = bf.monomial_masks(deg).unwrap().collect();
+            assert_eq!(masks.len(), comb(6, deg));
+            assert!(masks.iter().all(|&mask| weight(mask) == deg));
+            assert!(masks.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        let bf = BF::zero(3).unwrap();
+        let masks: Vec<usize> = bf.monomial_masks(2).unwrap().collect();
+        assert_eq!(masks, vec![0b011, 0b101, 0b110]);
+
+        assert!(matches!(bf.monomial_masks(4), Err(BFError::InvalidDeg(4))));
+    }
+
+    #[test]
+    fn chow_parameters_works() {
+        // majority of 3 arguments
+        let bf = BF::from_str("00010111").unwrap();
+        assert_eq!(bf.chow_parameters(), vec![0, 4, 4, 4]);
+
+        let bf = BF::one(3).unwrap();
+        assert_eq!(bf.chow_parameters(), vec![-8, 0, 0, 0]);
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let wac = bf.walsh_adamar();
+            let chow = bf.chow_parameters();
+            assert_eq!(chow.len(), bf.args_amount + 1);
+            assert_eq!(chow[0], wac[0]);
+            for i in 0..bf.args_amount {
+                assert_eq!(chow[i + 1], wac[pow2(i)]);
+            }
+        }
+    }
+
+    #[test]
+    fn is_threshold_works() {
+        // majority
+        let bf = BF::from_str("00010111").unwrap();
+        assert!(bf.is_threshold().unwrap());
+
+        // parity
+        let bf = BF::from_str("01101001").unwrap();
+        assert!(!bf.is_threshold().unwrap());
+
+        // x1 + x2&x3 isn't unate in x1
+        let bf = BF::from_str("00011110").unwrap();
+        assert!(!bf.is_threshold().unwrap());
+
+        // x1&x2 + x3&x4 (over OR) is unate, but not threshold
+        let mut bf = BF::zero(4).unwrap();
+        for args in 0..pow2(4) {
+            if args & 0b0011 == 0b0011 || args & 0b1100 == 0b1100 {
+                bf.set(args).unwrap();
+            }
+        }
+        assert!(!bf.is_threshold().unwrap());
+
+        // not x1 and x2
+        let bf = BF::from_str("0010").unwrap();
+        assert!(bf.is_threshold().unwrap());
+
+        // 3 x1 + 2 x2 + x3 + x4 >= 4
+        let mut bf = BF::zero(4).unwrap();
+        for args in 0..pow2(4) {
+            let sum: usize = [3, 2, 1, 1]
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (args >> i) & 1 == 1)
+                .map(|(_, w)| w)
+                .sum();
+            if sum >= 4 {
+                bf.set(args).unwrap();
+            }
+        }
+        assert!(bf.is_threshold().unwrap());
+
+        assert!(BF::zero(6).unwrap().is_threshold().unwrap());
+        assert!(BF::one(6).unwrap().is_threshold().unwrap());
+
+        assert_eq!(
+            BF::zero(7).unwrap().is_threshold(),
+            Err(BFError::TooManyArgs {
+                args_amount: 7,
+                max: 6
+            })
+        );
+    }
+
+    #[test]
+    fn compose_affine_works() {
+        let identity = BM::from_str("100\n010\n001").unwrap();
+        // matrix and its inverse
+        let a = BM::from_str("110\n011\n001").unwrap();
+        let a_inv = BM::from_str("111\n011\n001").unwrap();
+
+        for _ in 0..10 {
+            let bf = BF::random(3).unwrap();
+            assert_eq!(bf.compose_affine(&identity, 0).unwrap(), bf);
+
+            let g = bf.compose_affine(&a, 0).unwrap();
+            assert_eq!(g.compose_affine(&a_inv, 0).unwrap(), bf);
+            assert_eq!(g.weight(), bf.weight());
+
+            let g = bf.compose_affine(&identity, 0b101).unwrap();
+            for args in 0..pow2(3) {
+                assert_eq!(g.eval(args), bf.eval(args ^ 0b101));
+            }
+        }
+
+        // x1&x2 composed with swap of arguments is the same
+        let bf = BF::from_str("00010000").unwrap();
+        let swap = BM::from_str("010\n100\n001").unwrap();
+        assert_eq!(bf.compose_affine(&swap, 0).unwrap(), bf);
+        let bf = BF::from_str("00000101").unwrap();
+        assert_eq!(bf.compose_affine(&swap, 0).unwrap().to_string(), "00000011");
+
+        let bm = BM::from_str("10\n01").unwrap();
+        assert_eq!(
+            bf.compose_affine(&bm, 0),
+            Err(BFError::DimMismatch {
+                expected: 3,
+                given: 2
+            })
+        );
+        assert_eq!(
+            bf.compose_affine(&identity, 8),
+            Err(BFError::ArgOutOfBounds {
+                given: 8,
+                bounds: 8
+            })
+        );
+    }
+
+    #[test]
+    fn affine_class_representative_works() {
+        // all quadratic functions of 2 arguments are affine equivalent to x1&x2
+        let class = [
+            "0001", "0010", "0100", "1000", "1110", "1101", "1011", "0111",
+        ];
+        for s in class {
+            let bf = BF::from_str(s).unwrap();
+            let repr = bf.affine_class_representative().unwrap();
+            assert_eq!(repr.to_string(), "0001");
+        }
+
+        // nonzero linear functions are equivalent to each other
+        for s in ["0110", "0101", "1010", "1100"] {
+            let bf = BF::from_str(s).unwrap();
+            let repr = bf.affine_class_representative().unwrap();
+            assert_eq!(repr.to_string(), "0011");
+        }
+
+        let a = BM::from_str("1100\n0110\n0011\n0001").unwrap();
+        for _ in 0..5 {
+            let bf = BF::random(4).unwrap();
+            let repr = bf.affine_class_representative().unwrap();
+            let g = bf.compose_affine(&a, 0b1010).unwrap().inverse();
+            assert_eq!(g.affine_class_representative().unwrap(), repr);
+        }
+
+        assert_eq!(
+            BF::zero(5).unwrap().affine_class_representative(),
+            Err(BFError::TooManyArgs {
+                args_amount: 5,
+                max: 4
+            })
+        );
+    }
+
+    #[test]
+    fn off_set_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.off_set(), vec![0, 3, 5, 6, 7]);
+
+        assert_eq!(BF::one(3).unwrap().off_set(), Vec::<usize>::new());
+        assert_eq!(BF::zero(2).unwrap().off_set(), vec![0, 1, 2, 3]);
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let off_set = bf.off_set();
+            assert_eq!(bf.weight() + off_set.len(), pow2(bf.args_amount));
+            assert!(off_set.iter().all(|&args| bf.eval(args) == 0));
+        }
+    }
+
+    #[test]
+    fn indicator_of_code_works() {
+        let generator = BM::from_str("1100\n0110\n1010").unwrap();
+        let bf = BF::indicator_of_code(&generator).unwrap();
+        assert_eq!(bf.weight(), pow2(generator.rank()));
+        assert_eq!(bf.to_string(), "1001011000000000");
+        assert!(bf.support_is_subspace());
+
+        for _ in 0..10 {
+            let generator = BM::random(3, 5).unwrap();
+            let bf = BF::indicator_of_code(&generator).unwrap();
+            assert_eq!(bf.args_amount, 5);
+            assert_eq!(bf.weight(), pow2(generator.rank()));
+        }
+    }
+
+    #[test]
+    fn diffusion_score_works() {
+        let bent = BF::from_str("0001000100011110").unwrap();
+        let linear = BF::from_str("0101010101010101").unwrap();
+        assert!(bent.diffusion_score() > linear.diffusion_score());
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let score = bf.diffusion_score();
+            assert!((0.0..=1.0).contains(&score));
+        }
+    }
+
+    #[test]
+    fn maximal_monomials_works() {
+        let bf = BF::from_str("11000110").unwrap();
+        assert_eq!(bf.anf(), "1 + x2 + x1 + x3&x1");
+        assert_eq!(bf.maximal_monomials(), vec![vec![2], vec![3, 1]]);
+
+        let bf = BF::from_str("0001000100011110000100010001111000010001000111101110111011100001")
+            .unwrap();
+        assert_eq!(
+            bf.maximal_monomials(),
+            vec![vec![6, 5], vec![4, 3], vec![2, 1]]
+        );
+
+        assert_eq!(
+            BF::one(3).unwrap().maximal_monomials(),
+            vec![Vec::<usize>::new()]
+        );
+        assert!(BF::zero(3).unwrap().maximal_monomials().is_empty());
+    }
+
+    #[test]
+    fn estimated_bias_works() {
+        let bf = BF::from_str("0110100110010110").unwrap();
+        let (p, std_err) = bf.estimated_bias(10000);
+        assert!((p - 0.5).abs() < 0.05);
+        assert!(std_err > 0.0 && std_err < 0.01);
+
+        assert_eq!(BF::one(5).unwrap().estimated_bias(100), (1.0, 0.0));
+        assert_eq!(BF::zero(5).unwrap().estimated_bias(100), (0.0, 0.0));
+
+        let (p, std_err) = bf.estimated_bias(0);
+        assert!(p.is_nan() && std_err.is_nan());
+    }
+
+    #[test]
+    fn restrict_to_parity_works() {
+        // even: 0, 3, 5, 6; odd: 1, 2, 4, 7
+        let bf = BF::from_str("10110100").unwrap();
+        assert_eq!(bf.restrict_to_parity(true).unwrap().to_string(), "1110");
+        assert_eq!(bf.restrict_to_parity(false).unwrap().to_string(), "0100");
+
+        for n in 2..=7 {
+            let bf = BF::random(n).unwrap();
+            let even = bf.restrict_to_parity(true).unwrap();
+            let odd = bf.restrict_to_parity(false).unwrap();
+            assert_eq!(even.weight() + odd.weight(), bf.weight());
+
+            let (mut e, mut o) = (0, 0);
+            for args in 0..pow2(bf.args_amount) {
+                if weight(args) & 1 == 0 {
+                    assert_eq!(even.eval(e), bf.eval(args));
+                    e += 1;
+                } else {
+                    assert_eq!(odd.eval(o), bf.eval(args));
+                    o += 1;
+                }
+            }
+        }
+
+        let bf = BF::from_str("01").unwrap();
+        assert_eq!(bf.restrict_to_parity(true), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn walsh_energy_at_level_works() {
+        // x1 + x2 + x3 is 2-resilient
+        let bf = BF::from_str("01101001").unwrap();
+        assert_eq!(bf.walsh_energy_at_level(0), 0);
+        assert_eq!(bf.walsh_energy_at_level(1), 0);
+        assert_eq!(bf.walsh_energy_at_level(2), 0);
+        assert_eq!(bf.walsh_energy_at_level(3), 64);
+        assert_eq!(bf.walsh_energy_at_level(4), 0);
+
+        // sum of energies over all levels is 2^(2n)
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let total: u128 = (0..=bf.args_amount)
+                .map(|k| bf.walsh_energy_at_level(k))
+                .sum();
+            assert_eq!(total, 1 << (2 * bf.args_amount));
+        }
+    }
+
+    #[test]
+    fn direct_sum_power_works() {
+        let seed = BF::from_str("0001").unwrap();
+
+        let bf = BF::direct_sum_power(&seed, 2).unwrap();
+        assert_eq!(bf.to_string(), "0001000100011110");
+
+        let bf = BF::direct_sum_power(&seed, 3).unwrap();
+        assert_eq!(bf.args_amount, 6);
+        assert!(bf.walsh_adamar().iter().all(|coef| coef.abs() == 8));
+        assert_eq!(bf.deg(), 2);
+
+        assert_eq!(BF::direct_sum_power(&seed, 1).unwrap(), seed);
+        assert_eq!(BF::direct_sum_power(&seed, 0), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn mobius_up_to_degree_works() {
+        for n in 1..=7 {
+            let bf = BF::random(n).unwrap();
+            let mut mob = bf.clone();
+            mob.mobius();
+
+            for max_deg in 0..=bf.args_amount {
+                let expected: Vec<usize> = (0..pow2(bf.args_amount))
+                    .filter(|&mask| weight(mask) <= max_deg && mob.eval(mask) == 1)
+                    .collect();
+                assert_eq!(bf.mobius_up_to_degree(max_deg).unwrap(), expected);
+            }
+        }
+
+        let bf = BF::from_str("11000110").unwrap();
+        assert_eq!(bf.mobius_up_to_degree(1).unwrap(), vec![0, 2, 4]);
+        assert_eq!(bf.mobius_up_to_degree(4), Err(BFError::InvalidDeg(4)));
+    }
+
+    #[test]
+    fn anf_coefficients_works() {
+        let bf = BF::from_str("11000110").unwrap();
+        assert_eq!(
+            bf.anf_coefficients(),
+            vec![true, false, true, false, true, true, false, false]
+        );
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let coefs = bf.anf_coefficients();
+            assert_eq!(coefs.len(), pow2(bf.args_amount));
+            assert_eq!(coefs.iter().filter(|&&c| c).count(), bf.anf_weight());
+        }
+    }
+
+    #[test]
+    fn halves_work() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.lower_half().unwrap().to_string(), "0110");
+        assert_eq!(bf.upper_half().unwrap().to_string(), "1000");
+
+        for n in 2..=9 {
+            let bf = BF::random(n).unwrap();
+            let lower = bf.lower_half().unwrap();
+            let upper = bf.upper_half().unwrap();
+            let half = pow2(bf.args_amount - 1);
+            for args in 0..half {
+                assert_eq!(lower.eval(args), bf.eval(args));
+                assert_eq!(upper.eval(args), bf.eval(args + half));
+            }
+            assert_eq!(lower.weight() + upper.weight(), bf.weight());
+        }
+
+        let bf = BF::from_str("01").unwrap();
+        assert_eq!(bf.lower_half(), Err(BFError::NoArgs));
+        assert_eq!(bf.upper_half(), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn cross_correlation_works() {
+        for n in 1..=5 {
+            let f = BF::random(n).unwrap();
+            let g = BF::random(n).unwrap();
+            let ccv = f.cross_correlation(&g).unwrap();
+            for (a, coef) in ccv.iter().enumerate() {
+                let expected: i64 = (0..pow2(f.args_amount))
+                    .map(|x| 1 - 2 * (f.eval(x) ^ g.eval(x ^ a)) as i64)
+                    .sum();
+                assert_eq!(*coef, expected);
+            }
+            assert_eq!(f.cross_correlation(&f).unwrap(), f.autocor());
+        }
+
+        let f = BF::from_str("0001").unwrap();
+        let g = BF::from_str("0110").unwrap();
+        assert_eq!(f.cross_correlation(&g).unwrap(), vec![-2, 2, 2, -2]);
+        assert_eq!(cross_correlation_max(&[f.clone(), g.clone()]).unwrap(), 2);
+        assert_eq!(cross_correlation_max(std::slice::from_ref(&f)).unwrap(), 0);
+
+        let h = BF::zero(3).unwrap();
+        assert_eq!(
+            cross_correlation_max(&[f, h]),
+            Err(BFError::DimMismatch {
+                expected: 2,
+                given: 3
+            })
+        );
+    }
+
+    #[test]
+    fn is_constant_works() {
+        assert_eq!(BF::zero(5).unwrap().is_constant(), Some(0));
+        assert_eq!(BF::one(5).unwrap().is_constant(), Some(1));
+        assert_eq!(BF::one(1).unwrap().is_constant(), Some(1));
+        assert_eq!(BF::from_str("0110").unwrap().is_constant(), None);
+        assert_eq!(BF::from_str("0001").unwrap().is_constant(), None);
+    }
+
+    #[test]
+    fn monomial_functions_works() {
+        let bf = BF::from_str("11000110").unwrap();
+        let monomials: Vec<String> = bf
+            .monomial_functions()
+            .iter()
+            .map(|m| m.to_string())
+            .collect();
+        assert_eq!(
+            monomials,
+            vec!["11111111", "00110011", "00001111", "00000101"]
+        );
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let monomials = bf.monomial_functions();
+            assert_eq!(monomials.len(), bf.anf_weight());
+
+            let mut sum = BF::zero(n).unwrap();
+            for monomial in &monomials {
+                assert_eq!(monomial.anf_weight(), 1);
+                for (s, v) in sum.values.iter_mut().zip(monomial.values.iter()) {
+                    *s ^= v;
+                }
+            }
+            assert_eq!(sum, bf);
+        }
+
+        assert!(BF::zero(3).unwrap().monomial_functions().is_empty());
+    }
+
+    #[test]
+    fn half_weights_work() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.weight_of_lower_half(), 2);
+        assert_eq!(bf.weight_of_upper_half(), 1);
+
+        let bf = BF::from_str("10").unwrap();
+        assert_eq!(bf.weight_of_lower_half(), 1);
+        assert_eq!(bf.weight_of_upper_half(), 0);
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            let (lower, upper) = (bf.weight_of_lower_half(), bf.weight_of_upper_half());
+            assert_eq!(lower + upper, bf.weight());
+            if n > 1 {
+                assert_eq!(lower, bf.lower_half().unwrap().weight());
+            }
+        }
+    }
+
+    #[test]
+    fn eval_assignment_works() {
+        // x1&x2 + x3
+        let bf = BF::from_str("01010110").unwrap();
+        assert_eq!(bf.anf(), "x3 + x2&x1");
+
+        for args in 0..pow2(3) {
+            let assignment: HashMap<usize, u8> = (1..=3)
+                .map(|var| (var, ((args >> (3 - var)) & 1) as u8))
+                .collect();
+            assert_eq!(bf.eval_assignment(&assignment).unwrap(), bf.eval(args));
+        }
+
+        let assignment = HashMap::from([(1, 1), (2, 1), (3, 0)]);
+        assert_eq!(bf.eval_assignment(&assignment).unwrap(), 1);
+
+        let assignment = HashMap::from([(1, 1), (3, 0)]);
+        assert_eq!(bf.eval_assignment(&assignment), Err(BFError::MissingVar(2)));
+
+        let assignment = HashMap::from([(1, 1), (2, 1), (3, 0), (4, 0)]);
+        assert_eq!(
+            bf.eval_assignment(&assignment),
+            Err(BFError::VarOutOfBounds {
+                given: 4,
+                bounds: 3
+            })
+        );
+
+        let assignment = HashMap::from([(1, 1), (2, 2), (3, 0)]);
+        assert_eq!(bf.eval_assignment(&assignment), Err(BFError::InvalidBit(2)));
+    }
+
+    #[test]
+    fn restricted_walsh_works() {
+        for n in 2..=7 {
+            let bf = BF::random(n).unwrap();
+            for var in 0..n {
+                for value in 0..=1 {
+                    let mut restricted = BF::zero(n - 1).unwrap();
+                    let low_mask = pow2(var) - 1;
+                    for args in 0..pow2(n - 1) {
+                        let full = (args & low_mask)
+                            | ((args & !low_mask) << 1)
+                            | (value as usize * pow2(var));
+                        if bf.eval(full) == 1 {
+                            restricted.set(args).unwrap();
+                        }
+                    }
+
+                    assert_eq!(
+                        bf.restricted_walsh(var, value).unwrap(),
+                        restricted.walsh_adamar()
+                    );
+                }
+            }
+        }
+
+        let bf = BF::from_str("0001").unwrap();
+        assert_eq!(bf.restricted_walsh(0, 1).unwrap(), vec![0, 2]);
+        assert_eq!(
+            bf.restricted_walsh(2, 0),
+            Err(BFError::VarOutOfBounds {
+                given: 2,
+                bounds: 2
+            })
+        );
+        assert_eq!(bf.restricted_walsh(0, 2), Err(BFError::InvalidBit(2)));
+
+        let bf = BF::from_str("01").unwrap();
+        assert_eq!(bf.restricted_walsh(0, 0), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn xor_works() {
+        for n in 1..=8 {
+            let f = BF::random(n).unwrap();
+            let g = BF::random(n).unwrap();
+            let zero = BF::zero(n).unwrap();
+
+            assert_eq!(f.clone() ^ f.clone(), zero);
+            assert_eq!(f.clone() ^ zero.clone(), f);
+
+            let h = f.clone() ^ g.clone();
+            for args in 0..pow2(n) {
+                assert_eq!(h.eval(args), f.eval(args) ^ g.eval(args));
+            }
+        }
+
+        let f = BF::zero(2).unwrap();
+        let g = BF::zero(3).unwrap();
+        assert_eq!(
+            f.xor(&g),
+            Err(BFError::DimMismatch {
+                expected: 2,
+                given: 3
+            })
+        );
+    }
+
+    #[test]
+    fn and_or_works() {
+        for n in 1..=8 {
+            let f = BF::random(n).unwrap();
+            let g = BF::random(n).unwrap();
+
+            assert_eq!(f.clone() & f.clone(), f);
+            assert_eq!(f.clone() | f.clone(), f);
+            assert_eq!(f.clone() | f.inverse(), BF::one(n).unwrap());
+            assert_eq!(f.clone() & f.inverse(), BF::zero(n).unwrap());
+
+            let and = f.clone() & g.clone();
+            let or = f.clone() | g.clone();
+            assert_eq!(and.weight() + or.weight(), f.weight() + g.weight());
+        }
+
+        let f = BF::zero(2).unwrap();
+        let g = BF::zero(3).unwrap();
+        let err = Err(BFError::DimMismatch {
+            expected: 2,
+            given: 3,
+        });
+        assert_eq!(f.try_and(&g), err);
+        assert_eq!(f.try_or(&g), err);
+    }
+
+    #[test]
+    fn balance_by_flips_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.balance_by_flips(4).unwrap().to_string(), "11101000");
+        assert_eq!(bf.balance_by_flips(1).unwrap().to_string(), "00001000");
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            for target in [0, pow2(n - 1), pow2(n)] {
+                let balanced = bf.balance_by_flips(target).unwrap();
+                assert_eq!(balanced.weight(), target);
+                let distance = (balanced ^ bf.clone()).weight();
+                assert_eq!(distance, bf.weight().abs_diff(target));
+            }
+        }
+
+        let bf = BF::zero(2).unwrap();
+        assert_eq!(
+            bf.balance_by_flips(5),
+            Err(BFError::ArgOutOfBounds {
+                given: 5,
+                bounds: 5
+            })
+        );
+    }
+
+    #[test]
+    fn is_maiorana_mcfarland_works() {
+        // <x, pi(y)> ^ g(y) for m = 2
+        let pi = [2, 0, 3, 1];
+        let g = [0, 1, 1, 0];
+        let mut bf = BF::zero(4).unwrap();
+        for args in 0..pow2(4) {
+            let (x, y) = (args & 0b11, args >> 2);
+            if (weight(x & pi[y]) + g[y]) & 1 == 1 {
+                bf.set(args).unwrap();
+            }
+        }
+        assert!(bf.is_maiorana_mcfarland());
+        assert!(bf.walsh_adamar().iter().all(|coef| coef.abs() == 4));
+
+        let bf = BF::from_str("0001000100011110").unwrap();
+        assert!(!bf.is_maiorana_mcfarland());
+        let bf = BF::from_str("0000010100110110").unwrap();
+        assert!(bf.is_maiorana_mcfarland());
+
+        assert!(!BF::zero(4).unwrap().is_maiorana_mcfarland());
+        assert!(!BF::from_str("00010110").unwrap().is_maiorana_mcfarland());
+    }
+
+    #[test]
+    fn not_works() {
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            let not = !bf.clone();
+            assert_eq!(not.weight(), pow2(n) - bf.weight());
+            assert_eq!(not, bf.complement());
+            assert_eq!(!not, bf);
+        }
+
+        assert_eq!(!BF::zero(2).unwrap(), BF::one(2).unwrap());
+        assert_eq!((!BF::from_str("0110").unwrap()).to_string(), "1001");
+    }
+
+    #[test]
+    fn distance_to_symmetric_works() {
+        assert_eq!(BF::from_str("00010111").unwrap().distance_to_symmetric(), 0);
+        assert_eq!(BF::from_str("01101001").unwrap().distance_to_symmetric(), 0);
+        assert_eq!(BF::one(4).unwrap().distance_to_symmetric(), 0);
+
+        // x1 differs from symmetric functions on one argument of each nonconstant level
+        assert_eq!(BF::from_str("01010101").unwrap().distance_to_symmetric(), 2);
+    }
+
+    #[test]
+    fn hamming_distance_works() {
+        for n in 1..10 {
+            let zero = BF::zero(n).unwrap();
+            let one = BF::one(n).unwrap();
+            assert_eq!(zero.hamming_distance(&one).unwrap(), pow2(n));
+
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.hamming_distance(&bf).unwrap(), 0);
+            assert_eq!(bf.hamming_distance(&zero).unwrap(), bf.weight());
+            assert_eq!(bf.hamming_distance(&one).unwrap(), pow2(n) - bf.weight());
+        }
+
+        let a = BF::from_str("01101001").unwrap();
+        let b = BF::from_str("01010101").unwrap();
+        assert_eq!(a.hamming_distance(&b).unwrap(), 4);
+
+        let c = BF::zero(2).unwrap();
+        assert_eq!(
+            a.hamming_distance(&c),
+            Err(BFError::DimMismatch {
+                expected: 3,
+                given: 2
+            })
+        );
+    }
+
+    #[test]
+    fn degree_lower_bound_from_walsh_works() {
+        assert_eq!(BF::zero(3).unwrap().degree_lower_bound_from_walsh(), 0);
+        assert_eq!(BF::one(3).unwrap().degree_lower_bound_from_walsh(), 0);
+        assert_eq!(
+            BF::from_str("0110")
+                .unwrap()
+                .degree_lower_bound_from_walsh(),
+            1
+        );
+
+        // bent function: all coefficients are ±4
+        let bf = BF::from_str("0001000100011110").unwrap();
+        assert_eq!(bf.degree_lower_bound_from_walsh(), 2);
+
+        // x1&x2&x3 has odd weight
+        let bf = BF::from_str("00000001").unwrap();
+        assert_eq!(bf.degree_lower_bound_from_walsh(), 3);
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            assert!(bf.deg() >= bf.degree_lower_bound_from_walsh());
+        }
+    }
+
+    #[test]
+    fn from_anf_works() {
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(BF::from_anf(&bf.anf(), n).unwrap(), bf);
+        }
+
+        assert_eq!(BF::from_anf("0", 3).unwrap(), BF::zero(3).unwrap());
+        assert_eq!(BF::from_anf("1", 3).unwrap(), BF::one(3).unwrap());
+        assert_eq!(
+            BF::from_anf("x1&x2 + x1 + x2&x1", 2).unwrap().to_string(),
+            "0011"
+        );
+        assert_eq!(
+            BF::from_anf("1 + x2 + x1 + x3&x1", 3).unwrap().to_string(),
+            "11000110"
+        );
+
+        assert_eq!(
+            BF::from_anf("x1 + x4", 3),
+            Err(BFError::VarOutOfBounds {
+                given: 4,
+                bounds: 3
+            })
+        );
+        assert_eq!(
+            BF::from_anf("x1 + y2", 3),
+            Err(BFError::InvalidAnf(String::from("y2")))
+        );
+        assert_eq!(
+            BF::from_anf("x1 + ", 3),
+            Err(BFError::InvalidAnf(String::new()))
+        );
+    }
+
+    #[test]
+    fn is_balanced_works() {
+        for n in 1..10 {
+            assert!(!BF::zero(n).unwrap().is_balanced());
+            assert!(!BF::one(n).unwrap().is_balanced());
+            assert!(BF::linear(n, 1).unwrap().is_balanced());
+        }
+
+        assert!(BF::from_str("01101001").unwrap().is_balanced());
+        assert!(BF::from_str("10").unwrap().is_balanced());
+        assert!(!BF::from_str("01101000").unwrap().is_balanced());
+    }
+
+    #[test]
+    fn linear_structures_works() {
+        // x1&x2 + x3 + x4: shifts in x3 and x4 give constant derivatives
+        let bf = BF::from_anf("x1&x2 + x3 + x4", 4).unwrap();
+        assert_eq!(bf.linear_structures(), vec![1, 2, 3]);
+        assert_eq!(bf.linear_structure_dimension(), 2);
+
+        for n in 1..6 {
+            let n_structures = pow2(n) - 1;
+            assert_eq!(BF::zero(n).unwrap().linear_structures().len(), n_structures);
+            assert_eq!(BF::linear(n, 1).unwrap().linear_structure_dimension(), n);
+        }
+
+        // bent function has no linear structures
+        let bf = BF::from_str("0001000100011110").unwrap();
+        assert!(bf.linear_structures().is_empty());
+        assert_eq!(bf.linear_structure_dimension(), 0);
+    }
+
+    #[test]
+    fn walsh_digest_works() {
+        // x1&x2 + x3 and x2&x3 + x1
+        let bf = BF::from_str("00011110").unwrap();
+        let permuted = BF::from_str("01010110").unwrap();
+        assert_eq!(bf.walsh_digest(), permuted.walsh_digest());
+        assert_eq!(bf.walsh_digest(), bf.inverse().walsh_digest());
+        assert_eq!(
+            bf.walsh_digest(),
+            (bf.clone() ^ BF::linear(3, 5).unwrap()).walsh_digest()
+        );
+        assert_eq!(bf.walsh_digest().len(), 16);
+
+        assert_ne!(
+            bf.walsh_digest(),
+            BF::from_str("00000001").unwrap().walsh_digest()
+        );
+        assert_ne!(bf.walsh_digest(), BF::zero(3).unwrap().walsh_digest());
+        assert_ne!(
+            BF::zero(3).unwrap().walsh_digest(),
+            BF::zero(4).unwrap().walsh_digest()
+        );
+    }
+
+    #[test]
+    fn is_bent_works() {
+        // x1&x2 + x3&x4
+        let bf = BF::from_str("0001000100011110").unwrap();
+        assert!(bf.is_bent());
+        assert!(bf.inverse().is_bent());
+        assert!(BF::from_str("0001").unwrap().is_bent());
+
+        assert!(!BF::from_str("01101001").unwrap().is_bent());
+        assert!(!BF::from_str("00010111").unwrap().is_bent());
+        assert!(!BF::zero(4).unwrap().is_bent());
+        assert!(!BF::linear(4, 3).unwrap().is_bent());
+
+        // bent functions are not balanced
+        for _ in 0..10 {
+            let bf = BF::random(4).unwrap().balance_by_flips(8).unwrap();
+            assert!(!bf.is_bent());
+        }
+    }
+
+    #[test]
+    fn absolute_indicator_works() {
+        // x1&x2 + x3&x4
+        let bf = BF::from_str("0001000100011110").unwrap();
+        assert_eq!(bf.absolute_indicator(), 0);
+
+        // x1&x2 + x3 has linear structure x3
+        let bf = BF::from_str("00011110").unwrap();
+        assert_eq!(bf.absolute_indicator(), 8);
+
+        // majority of three is self-dual: D_111 f = 1
+        let bf = BF::from_str("00010111").unwrap();
+        assert_eq!(bf.absolute_indicator(), 8);
+
+        // x1&x2&x3: derivatives differ from zero on two arguments
+        let bf = BF::from_str("00000001").unwrap();
+        assert_eq!(bf.absolute_indicator(), 4);
+
+        assert_eq!(BF::zero(3).unwrap().absolute_indicator(), 8);
+    }
+
+    #[test]
+    fn substitute_xor_works() {
+        // x1&x2 -> (x1 ^ x3)&x2
+        let bf = BF::from_anf("x1&x2", 3).unwrap();
+        let substituted = bf.substitute_xor(2, 0).unwrap();
+        assert_eq!(substituted, BF::from_anf("x1&x2 + x3&x2", 3).unwrap());
+
+        for n in 2..=6 {
+            let bf = BF::random(n).unwrap();
+            for target in 0..n {
+                let source = (target + 1) % n;
+                let once = bf.substitute_xor(target, source).unwrap();
+                assert_eq!(once.weight(), bf.weight());
+                assert_eq!(once.substitute_xor(target, source).unwrap(), bf);
+            }
+        }
+
+        let bf = BF::zero(3).unwrap();
+        assert_eq!(bf.substitute_xor(1, 1), Err(BFError::DuplicateVar(1)));
+        assert_eq!(
+            bf.substitute_xor(0, 3),
+            Err(BFError::VarOutOfBounds {
+                given: 3,
+                bounds: 3
+            })
+        );
+    }
+
+    #[test]
+    fn sum_of_squares_indicator_works() {
+        let bent = BF::from_str("0001000100011110").unwrap();
+        assert_eq!(bent.sum_of_squares_indicator(), 1 << 8);
+
+        let linear = BF::linear(4, 5).unwrap();
+        assert_eq!(linear.sum_of_squares_indicator(), 1 << 12);
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            let fourth_powers: u64 = bf
+                .walsh_adamar()
+                .iter()
+                .map(|&coef| coef.pow(4) as u64)
+                .sum();
+            assert_eq!(bf.sum_of_squares_indicator(), fourth_powers >> n);
+        }
+    }
+
+    #[test]
+    fn all_with_weight_works() {
+        for n in 1..=3 {
+            for w in 0..=pow2(n) {
+                let bfs: Vec<BF> = BF::all_with_weight(n, w).unwrap().collect();
+                assert_eq!(bfs.len(), comb(pow2(n), w));
+                assert!(bfs.iter().all(|bf| bf.weight() == w));
+                assert!(bfs.iter().tuple_windows().all(|(a, b)| a != b));
+            }
+        }
+
+        assert_eq!(BF::all_with_weight(5, 2).unwrap().count(), 496);
+        assert_eq!(
+            BF::all_with_weight(2, 1)
+                .unwrap()
+                .map(|bf| bf.to_string())
+                .collect_vec(),
+            vec!["1000", "0100", "0010", "0001"]
+        );
+
+        assert!(matches!(BF::all_with_weight(0, 0), Err(BFError::NoArgs)));
+        assert!(matches!(
+            BF::all_with_weight(6, 1),
+            Err(BFError::TooManyArgs {
+                args_amount: 6,
+                max: 5
+            })
+        ));
+        assert!(matches!(
+            BF::all_with_weight(2, 5),
+            Err(BFError::ArgOutOfBounds {
+                given: 5,
+                bounds: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn walsh_abs_variance_works() {
+        let bent = BF::from_str("0001000100011110").unwrap();
+        assert_eq!(bent.walsh_abs_variance(), 0.0);
+        assert_eq!(BF::zero(4).unwrap().walsh_abs_variance(), 15.0);
+
+        // |W| of majority of 3 arguments is 4 on half of masks and 0 on the rest
+        let majority = BF::from_str("00010111").unwrap();
+        assert_eq!(majority.walsh_abs_variance(), 4.0);
+
+        for n in 1..=8 {
+            let affine = BF::affine(n, pow2(n) - 1, 1).unwrap();
+            assert_eq!(affine.walsh_abs_variance(), (pow2(n) - 1) as f64);
+        }
+    }
+
+    #[test]
+    fn algebraic_immunity_works() {
+        // majority of 2 is x1&x2, annihilated by 1 + x1
+        assert_eq!(BF::from_str("0001").unwrap().algebraic_immunity(), 1);
+
+        // majority functions have optimal immunity ceil(n/2)
+        assert_eq!(BF::from_str("00010111").unwrap().algebraic_immunity(), 2);
+        let majority_5 = BF::from_str("00000001000101110001011101111111").unwrap();
+        assert_eq!(majority_5.algebraic_immunity(), 3);
+
+        assert_eq!(BF::linear(4, 5).unwrap().algebraic_immunity(), 1);
+        assert_eq!(BF::zero(3).unwrap().algebraic_immunity(), 0);
+        assert_eq!(BF::one(3).unwrap().algebraic_immunity(), 0);
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let ai = bf.algebraic_immunity();
+            assert!(ai <= n.div_ceil(2));
+            assert!(ai <= bf.deg());
+        }
+    }
+
+    #[test]
+    fn resiliency_works() {
+        assert_eq!(BF::from_str("01101001").unwrap().resiliency(), 2);
+        assert_eq!(BF::from_str("0110").unwrap().resiliency(), 1);
+        assert_eq!(BF::linear(4, 1).unwrap().resiliency(), 0);
+
+        // x1&x2 + x3 + x4 is balanced and 1-resilient
+        let bf = BF::from_anf("x1&x2 + x3 + x4", 4).unwrap();
+        assert_eq!(bf.resiliency(), 1);
+
+        assert_eq!(BF::zero(3).unwrap().resiliency(), -1);
+        assert_eq!(BF::one(3).unwrap().resiliency(), -1);
+        assert_eq!(BF::from_str("0001").unwrap().resiliency(), -1);
+    }
+
+    #[test]
+    fn to_gray_ordered_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.to_gray_ordered(), vec![0, 1, 0, 1, 0, 0, 0, 1]);
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            let gray_ordered = bf.to_gray_ordered();
+
+            let mut restored = BF::zero(n).unwrap();
+            for (i, &value) in gray_ordered.iter().enumerate() {
+                if value == 1 {
+                    restored.set(gray(i)).unwrap();
+                }
+            }
+            assert_eq!(restored, bf);
+        }
+    }
+
+    #[test]
+    fn derivative_works() {
+        // D_001 (x1&x2 + x3) = 1, D_100 (x1&x2 + x3) = x2
+        let bf = BF::from_anf("x1&x2 + x3", 3).unwrap();
+        assert_eq!(bf.derivative(1).unwrap(), BF::one(3).unwrap());
+        assert_eq!(bf.derivative(4).unwrap(), BF::from_anf("x2", 3).unwrap());
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.derivative(0).unwrap(), BF::zero(n).unwrap());
+
+            for a in [1, pow2(n) - 1, pow2(n) / 2, (pow2(n) / 2) | 1] {
+                let derivative = bf.derivative(a).unwrap();
+                assert_eq!(derivative.weight() % 2, 0);
+                for args in 0..pow2(n) {
+                    assert_eq!(derivative.eval(args), bf.eval(args) ^ bf.eval(args ^ a));
+                }
+            }
+        }
+
+        assert_eq!(
+            bf.derivative(8),
+            Err(BFError::ArgOutOfBounds {
+                given: 8,
+                bounds: 8
+            })
+        );
+    }
+
+    #[test]
+    fn to_sop_minimal_works() {
+        let majority = BF::from_str("00010111").unwrap();
+        assert_eq!(majority.to_sop_minimal().unwrap(), "x3&x2 + x3&x1 + x2&x1");
+
+        let bf = BF::from_str("0110").unwrap();
+        assert_eq!(bf.to_sop_minimal().unwrap(), "x2&!x1 + !x2&x1");
+
+        let bf = BF::from_anf("x1", 3).unwrap();
+        assert_eq!(bf.to_sop_minimal().unwrap(), "x1");
+
+        assert_eq!(BF::zero(3).unwrap().to_sop_minimal().unwrap(), "0");
+        assert_eq!(BF::one(3).unwrap().to_sop_minimal().unwrap(), "1");
+        assert_eq!(
+            BF::zero(9).unwrap().to_sop_minimal(),
+            Err(BFError::TooManyArgs {
+                args_amount: 9,
+                max: 8
+            })
+        );
+
+        // minimized form describes the same function
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let sop = bf.to_sop_minimal().unwrap();
+
+            let mut restored = BF::zero(n).unwrap();
+            for term in sop.split(" + ") {
+                let mut product = BF::one(n).unwrap();
+                for literal in term.split('&') {
+                    let var = BF::from_anf(literal.trim_start_matches('!'), n).unwrap();
+                    product = product & if literal.starts_with('!') { !var } else { var };
+                }
+                restored = restored | product;
+            }
+            assert_eq!(restored, bf);
+        }
+    }
+
+    #[test]
+    fn restrict_works() {
+        let bf = BF::from_str("11000110").unwrap();
+        let restrictions: Vec<String> = (0..3)
+            .flat_map(|var| [0, 1].map(|value| bf.restrict(var, value).unwrap().to_string()))
+            .collect();
+        assert_eq!(
+            restrictions,
+            vec!["1001", "1010", "1101", "0010", "1100", "0110"]
+        );
+
+        let parity = BF::from_str("01101001").unwrap();
+        for var in 0..3 {
+            assert_eq!(parity.restrict(var, 0).unwrap().to_string(), "0110");
+            assert_eq!(parity.restrict(var, 1).unwrap().to_string(), "1001");
+        }
+
+        for n in 2..=8 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.restrict(n - 1, 0).unwrap(), bf.lower_half().unwrap());
+            assert_eq!(bf.restrict(n - 1, 1).unwrap(), bf.upper_half().unwrap());
+            for var in 0..n {
+                let restricted = bf.restrict(var, 1).unwrap();
+                assert_eq!(
+                    restricted.walsh_adamar(),
+                    bf.restricted_walsh(var, 1).unwrap()
+                );
+            }
+        }
+
+        assert_eq!(
+            bf.restrict(3, 0),
+            Err(BFError::VarOutOfBounds {
+                given: 3,
+                bounds: 3
+            })
+        );
+        assert_eq!(bf.restrict(0, 2), Err(BFError::InvalidBit(2)));
+        assert_eq!(BF::zero(1).unwrap().restrict(0, 0), Err(BFError::NoArgs));
+    }
 
-        let mut bf = BF::from_str("0000").expect("can convert");
-        bf.mobius();
-        assert_eq!(bf.anf(), "0");
+    #[test]
+    fn prime_implicant_count_works() {
+        assert_eq!(
+            BF::from_str("00010111").unwrap().prime_implicant_count(),
+            Ok(3)
+        );
+        assert_eq!(BF::from_str("0110").unwrap().prime_implicant_count(), Ok(2));
+        assert_eq!(BF::one(3).unwrap().prime_implicant_count(), Ok(1));
+        assert_eq!(BF::zero(3).unwrap().prime_implicant_count(), Ok(0));
+
+        // cyclic function: all arguments except 000 and 111
+        let bf = BF::from_str("01111110").unwrap();
+        assert_eq!(bf.prime_implicant_count(), Ok(6));
+
+        // parity: every minterm is prime
+        assert_eq!(BF::linear(4, 15).unwrap().prime_implicant_count(), Ok(8));
+
+        assert!(matches!(
+            BF::zero(9).unwrap().prime_implicant_count(),
+            Err(BFError::TooManyArgs { .. })
+        ));
     }
 
     #[test]
-    fn degree_works() {
-        let bf = BF::one(16).unwrap();
-        assert_eq!(bf.deg(), 0);
+    fn permute_vars_works() {
+        // x1&x2 + x3 -> x2&x3 + x1
+        let bf = BF::from_str("00011110").unwrap();
+        assert_eq!(bf.permute_vars(&[2, 0, 1]).unwrap().to_string(), "01010110");
+
+        for n in 1..=6 {
+            let bf = BF::random(n).unwrap();
+            let identity: Vec<usize> = (0..n).collect();
+            assert_eq!(bf.permute_vars(&identity).unwrap(), bf);
+
+            for shift in 1..n {
+                let perm: Vec<usize> = (0..n).map(|k| (k + shift) % n).collect();
+                let mut inverse = vec![0; n];
+                for (k, &p) in perm.iter().enumerate() {
+                    inverse[p] = k;
+                }
 
-        let bf = BF::zero(16).unwrap();
-        assert_eq!(bf.deg(), 0);
+                let permuted = bf.permute_vars(&perm).unwrap();
+                assert_eq!(permuted.weight(), bf.weight());
+                assert_eq!(permuted.permute_vars(&inverse).unwrap(), bf);
+            }
+        }
 
-        let bf = BF::from_str("0001").unwrap();
-        assert_eq!(bf.deg(), 2);
+        assert_eq!(
+            bf.permute_vars(&[0, 1, 1]),
+            Err(BFError::InvalidPermutation(vec![0, 1, 1]))
+        );
+        assert_eq!(
+            bf.permute_vars(&[0, 1]),
+            Err(BFError::InvalidPermutation(vec![0, 1]))
+        );
+    }
 
-        let bf = BF::from_str("00000001").unwrap();
-        assert_eq!(bf.deg(), 3);
+    #[test]
+    fn rm_approximation_error_works() {
+        // dropping x3&x1 changes value on two arguments
+        let bf = BF::from_str("11000110").unwrap();
+        assert_eq!(bf.rm_approximation_error(1), Ok(2));
+        assert_eq!(bf.rm_approximation_error(0), Ok(4));
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.rm_approximation_error(n), Ok(0));
+            // constant term of arithmetic normal form is f(0)
+            let expected = match bf.eval(0) {
+                0 => bf.weight(),
+                _ => pow2(n) - bf.weight(),
+            };
+            assert_eq!(bf.rm_approximation_error(0), Ok(expected));
+        }
 
-        let bf = "1".to_owned() + &"0".repeat(pow2(16) - 1);
-        let bf = BF::from_str(&bf).unwrap();
-        assert_eq!(bf.deg(), 16);
+        assert_eq!(bf.rm_approximation_error(4), Err(BFError::InvalidDeg(4)));
     }
 
     #[test]
-    fn walsh_adamar_works() {
-        let bf = BF::from_str("0110").unwrap();
-        let wac = bf.walsh_adamar();
-        assert_eq!(wac, vec![0, 0, 0, 4]);
+    fn negate_vars_works() {
+        // x1&x2 + x3 -> x1&(x2 ^ 1) + x3
+        let bf = BF::from_str("00011110").unwrap();
+        assert_eq!(bf.negate_vars(2).unwrap().to_string(), "01001011");
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            let all = pow2(n) - 1;
+            assert_eq!(bf.negate_vars(all).unwrap().negate_vars(all).unwrap(), bf);
+
+            let mut identity = BM::zero(n, n).unwrap();
+            for k in 0..n {
+                identity.set(k, k);
+            }
+
+            for mask in [0, 1, all, all / 2, pow2(n) / 2] {
+                let negated = bf.negate_vars(mask).unwrap();
+                assert_eq!(negated.weight(), bf.weight());
+                for args in 0..pow2(n) {
+                    assert_eq!(negated.eval(args), bf.eval(args ^ mask));
+                }
+                assert_eq!(negated, bf.compose_affine(&identity, mask).unwrap());
+            }
+        }
 
-        let bf = BF::from_str("0001000100011110").unwrap();
-        let wac = bf.walsh_adamar();
         assert_eq!(
-            wac,
-            vec![4, 4, 4, -4, 4, 4, 4, -4, 4, 4, 4, -4, -4, -4, -4, 4]
+            bf.negate_vars(8),
+            Err(BFError::ArgOutOfBounds {
+                given: 8,
+                bounds: 8
+            })
         );
+    }
 
-        for i in 1..=3 {
-            let bf = BF::one(i * 3).unwrap();
-            let wac = bf.walsh_adamar();
-            let mut expected = vec![0i32; pow2(i * 3)];
-            expected[0] = -(pow2(i * 3) as i32);
-            assert_eq!(wac, expected);
+    #[test]
+    fn direct_sum_works() {
+        // (x1 + x2) and (1 + x1) become (x3 + x4) and (1 + x1) in 4 arguments
+        let f = BF::from_anf("x1 + x2", 2).unwrap();
+        let g = BF::from_anf("1 + x1", 2).unwrap();
+        let h = f.direct_sum(&g).unwrap();
+        assert_eq!(h.args_amount, 4);
+        assert_eq!(h.anf(), "1 + x4 + x3 + x1");
+
+        // x1&x2 + x3&x4 is a direct sum of two bent functions
+        let and = BF::from_str("0001").unwrap();
+        assert_eq!(
+            and.direct_sum(&and).unwrap().to_string(),
+            "0001000100011110"
+        );
+
+        for (n, m) in (1..=4).cartesian_product(1..=3) {
+            let f = BF::random(n).unwrap();
+            let g = BF::random(m).unwrap();
+            let h = f.direct_sum(&g).unwrap();
+            let (wf, wg) = (f.walsh_adamar(), g.walsh_adamar());
+            for (w, coef) in h.walsh_adamar().into_iter().enumerate() {
+                let low = w & (pow2(f.args_amount) - 1);
+                assert_eq!(coef, wf[low] * wg[w >> f.args_amount]);
+            }
         }
+
+        let big = BF {
+            values: vec![],
+            args_amount: 20,
+        };
+        assert_eq!(
+            big.direct_sum(&big),
+            Err(BFError::TooManyArgs {
+                args_amount: 40,
+                max: 32
+            })
+        );
     }
 
     #[test]
-    fn cor_works() {
-        let args_amount = 16;
+    fn has_no_linear_structures_works() {
+        let bent = BF::from_str("0001000100011110").unwrap();
+        assert!(bent.has_no_linear_structures());
+
+        // x1&x2 + x3 has linear structure in x3
+        assert!(!BF::from_str("00011110").unwrap().has_no_linear_structures());
+        assert!(!BF::linear(3, 5).unwrap().has_no_linear_structures());
+        assert!(!BF::zero(2).unwrap().has_no_linear_structures());
+    }
 
-        let bf = BF::one(args_amount).unwrap();
-        assert_eq!(bf.cor(), args_amount);
+    #[test]
+    fn concatenate_works() {
+        let f = BF::from_str("0110").unwrap();
+        let g = BF::from_str("0001").unwrap();
+        assert_eq!(f.concatenate(&g).unwrap().to_string(), "01100001");
+
+        for n in 1..=8 {
+            let f = BF::random(n).unwrap();
+            let g = BF::random(n).unwrap();
+
+            let h = f.concatenate(&g).unwrap();
+            assert_eq!(h.lower_half().unwrap(), f);
+            assert_eq!(h.upper_half().unwrap(), g);
+
+            // the new argument is fictive
+            let h = f.concatenate(&f).unwrap();
+            assert_eq!(h.derivative(pow2(n)).unwrap(), BF::zero(n + 1).unwrap());
+        }
 
-        let bf = BF::zero(args_amount).unwrap();
-        assert_eq!(bf.cor(), args_amount);
+        assert_eq!(
+            f.concatenate(&BF::zero(3).unwrap()),
+            Err(BFError::DimMismatch {
+                expected: 2,
+                given: 3
+            })
+        );
 
-        let bf = BF::from_str("01101001").unwrap();
-        assert_eq!(bf.cor(), 2);
+        // only arity is checked, so truth table isn't needed
+        let widest = BF {
+            values: Vec::new(),
+            args_amount: MAX_ARGS,
+        };
+        assert_eq!(
+            widest.concatenate(&widest),
+            Err(BFError::TooManyArgs {
+                args_amount: MAX_ARGS + 1,
+                max: MAX_ARGS
+            })
+        );
     }
 
     #[test]
-    fn nonlinearity_works() {
-        let bf = BF::from_str("01111010").unwrap();
-        assert_eq!(bf.nonlinearity(), 1);
+    fn xor_convolution_works() {
+        let f = BF::from_str("0110").unwrap();
+        let g = BF::from_str("0001").unwrap();
+        assert_eq!(f.xor_convolution(&g).unwrap(), vec![0, 1, 1, 0]);
+
+        for n in 1..=7 {
+            let f = BF::random(n).unwrap();
+            let g = BF::random(n).unwrap();
+
+            let convolution = f.xor_convolution(&g).unwrap();
+            for (z, &h) in convolution.iter().enumerate() {
+                let naive: i64 = (0..pow2(n))
+                    .map(|x| (f.eval(x) * g.eval(x ^ z)) as i64)
+                    .sum();
+                assert_eq!(h, naive);
+            }
 
-        let bf = BF::from_str("01100000").unwrap();
-        assert_eq!(bf.nonlinearity(), 2);
+            // C(a) = 2^n - 4 * (weight - h(a))
+            let weight = f.weight() as i64;
+            let expected: Vec<i64> = f
+                .xor_convolution(&f)
+                .unwrap()
+                .into_iter()
+                .map(|h| pow2(n) as i64 - 4 * (weight - h))
+                .collect();
+            assert_eq!(f.autocor(), expected);
+        }
 
-        let bf = BF::from_str("00000000").unwrap();
-        assert_eq!(bf.nonlinearity(), 0);
+        assert!(matches!(
+            f.xor_convolution(&BF::zero(3).unwrap()),
+            Err(BFError::DimMismatch {
+                expected: 2,
+                given: 3
+            })
+        ));
     }
 
     #[test]
-    fn inverse_works() {
-        let bf = BF::from_str("1100").unwrap();
-        let inv = bf.inverse();
-        assert_eq!(inv.to_string(), "0011");
+    fn affine_walsh_works() {
+        assert_eq!(BF::affine_walsh(2, 1, 0).unwrap(), vec![0, 4, 0, 0]);
+        assert_eq!(BF::affine_walsh(2, 0, 1).unwrap(), vec![-4, 0, 0, 0]);
+
+        for n in 1..6 {
+            for mask in 0..pow2(n) {
+                let bf = BF::linear(n, mask).unwrap();
+                assert_eq!(BF::affine_walsh(n, mask, 0).unwrap(), bf.walsh_adamar());
+                assert_eq!(
+                    BF::affine_walsh(n, mask, 1).unwrap(),
+                    bf.inverse().walsh_adamar()
+                );
+            }
+        }
+
+        assert_eq!(BF::affine_walsh(0, 0, 0), Err(BFError::NoArgs));
+        assert_eq!(
+            BF::affine_walsh(64, 0, 0),
+            Err(BFError::TooManyArgs {
+                args_amount: 64,
+                max: MAX_ARGS
+            })
+        );
+        assert_eq!(
+            BF::affine_walsh(2, 4, 0),
+            Err(BFError::ArgOutOfBounds {
+                given: 4,
+                bounds: 4
+            })
+        );
+        assert_eq!(BF::affine_walsh(2, 1, 2), Err(BFError::InvalidBit(2)));
     }
 
     #[test]
-    fn best_affine_approx_works() {
-        let bf = BF::from_str("01111010").unwrap();
-        let approx = bf.best_affine_approx();
-        assert_eq!(approx.to_string(), "01011010");
+    fn support_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.support().collect_vec(), vec![1, 2, 4]);
+        assert_eq!(BF::zero(4).unwrap().support().count(), 0);
+        assert_eq!(
+            BF::one(4).unwrap().support().collect_vec(),
+            (0..16).collect_vec()
+        );
 
-        let bf = BF::zero(3).unwrap();
-        let approx = bf.best_affine_approx();
-        assert_eq!(approx.to_string(), "00000000");
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            let support = bf.support().collect_vec();
+            assert_eq!(support.len(), bf.weight());
 
-        let bf = BF::one(3).unwrap();
-        let approx = bf.best_affine_approx();
-        assert_eq!(approx.to_string(), "11111111");
+            let expected = (0..pow2(n))
+                .filter(|&args| bf.eval(args) == 1)
+                .collect_vec();
+            assert_eq!(support, expected);
+        }
+    }
 
-        let bf = BF::from_str("01010101").unwrap();
-        let approx = bf.best_affine_approx();
-        assert_eq!(approx.to_string(), "01010101");
+    #[test]
+    fn iter_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(
+            bf.iter().collect_vec(),
+            vec![false, true, true, false, true, false, false, false]
+        );
 
-        let bf = BF::from_str("01100000").unwrap();
-        let approx = bf.best_affine_approx();
-        assert_eq!(approx.to_string(), "00000000");
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.iter().filter(|&value| value).count(), bf.weight());
+            assert_eq!(
+                bf.iter().positions(|value| value).collect_vec(),
+                bf.support().collect_vec()
+            );
+        }
     }
 
     #[test]
-    fn autocor_works() {
-        let bf = BF::from_str("0001").unwrap();
-        let acv = bf.autocor();
-        assert_eq!(acv, &[4, 0, 0, 0]);
+    fn implicants_works() {
+        // majority of three: x2&x3, x1&x3 and x1&x2
+        let bf = BF::from_str("00010111").unwrap();
+        assert_eq!(bf.implicants(1).unwrap(), vec![(6, 1), (5, 2), (3, 4)]);
+        assert_eq!(
+            bf.implicants(0).unwrap(),
+            bf.support().map(|args| (args, 0)).collect_vec()
+        );
+        assert!(bf.implicants(2).unwrap().is_empty());
+
+        let bf = BF::from_anf("x1", 3).unwrap();
+        assert_eq!(bf.implicants(2).unwrap(), vec![(4, 3)]);
+        assert_eq!(BF::one(3).unwrap().implicants(3).unwrap(), vec![(0, 7)]);
+        assert!(BF::zero(3).unwrap().implicants(0).unwrap().is_empty());
+
+        assert_eq!(bf.implicants(4), Err(BFError::InvalidDeg(4)));
     }
 
     #[test]
-    fn pc_cor_works() {
+    fn hex_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        assert_eq!(bf.to_hex(), "61");
+        assert_eq!(BF::from_str("01").unwrap().to_hex(), "2");
+        assert_eq!(BF::one(4).unwrap().to_hex(), "ffff");
+        assert_eq!(BF::from_hex("61", 3).unwrap(), bf);
+        assert_eq!(BF::from_hex("FfFf", 4).unwrap(), BF::one(4).unwrap());
+
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(BF::from_hex(&bf.to_hex(), n).unwrap(), bf);
+        }
+
+        assert_eq!(
+            BF::from_hex("610", 3),
+            Err(BFError::InvalidLength {
+                given: 3,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            BF::from_hex("6g", 3),
+            Err(BFError::InvalidString(String::from("6g")))
+        );
+        assert_eq!(BF::from_hex("4", 1), Err(BFError::UnusedBitsSet));
+        assert_eq!(BF::from_hex("", 0), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn walsh_sign_changes_works() {
+        // x1&x2: W = [2, 2, 2, -2], in Gray order [2, 2, -2, 2]
         let bf = BF::from_str("0001").unwrap();
-        let pcd = bf.pc_deg();
-        assert_eq!(pcd, 2);
+        assert_eq!(bf.walsh_sign_changes(), 2);
+
+        // x1&x2 + x3&x4 has sign changes, linear function has a single spike
+        let bent = BF::from_str("0001000100011110").unwrap();
+        assert_eq!(bent.walsh_sign_changes(), 10);
+        assert_eq!(BF::linear(4, 5).unwrap().walsh_sign_changes(), 0);
+        assert_eq!(BF::one(4).unwrap().walsh_sign_changes(), 0);
     }
 
     #[test]
-    fn comp_nonlinearity_works() {
-        let bf = BF::from_str("0001000100011110").unwrap();
-        let cn = bf.comp_nonlinearity();
-        println!("CNf = {cn}");
+    fn bytes_works() {
+        // format doesn't depend on size of Value
+        let bf = BF::from_str("0110100000000001").unwrap();
+        assert_eq!(bf.to_bytes(), vec![4, 0b0001_0110, 0b1000_0000]);
+        assert_eq!(BF::from_bytes(&[4, 0b0001_0110, 0b1000_0000]).unwrap(), bf);
+
+        let bf = BF::from_str("01").unwrap();
+        assert_eq!(bf.to_bytes(), vec![1, 0b10]);
+
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            let bytes = bf.to_bytes();
+            assert_eq!(bytes.len(), 1 + pow2(n).div_ceil(8));
+            assert_eq!(BF::from_bytes(&bytes).unwrap(), bf);
+        }
 
-        let bf = BF::from_str("0001000100011110000100010001111000010001000111101110111011100001")
-            .unwrap();
-        let cn = bf.comp_nonlinearity();
-        println!("CNf = {cn}");
+        assert_eq!(
+            BF::from_bytes(&[]),
+            Err(BFError::InvalidLength {
+                given: 0,
+                expected: 1
+            })
+        );
+        assert_eq!(
+            BF::from_bytes(&[4, 0]),
+            Err(BFError::InvalidLength {
+                given: 2,
+                expected: 3
+            })
+        );
+        // widest header without a table is rejected before allocating it
+        assert_eq!(
+            BF::from_bytes(&[MAX_ARGS as u8]),
+            Err(BFError::InvalidLength {
+                given: 1,
+                expected: 1 + pow2(MAX_ARGS) / 8
+            })
+        );
+        assert_eq!(BF::from_bytes(&[0]), Err(BFError::NoArgs));
+        assert_eq!(BF::from_bytes(&[2, 0b1_0000]), Err(BFError::UnusedBitsSet));
+        assert_eq!(
+            BF::from_bytes(&[40]),
+            Err(BFError::TooManyArgs {
+                args_amount: 40,
+                max: 32
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_be_works() {
+        let bf = BF::from_str_be("0001").unwrap();
+        assert_eq!(bf.to_string(), "1000");
+        assert_eq!(bf.eval(0), 1);
+
+        for s in ["01", "0110", "01101000", "1100011000010111"] {
+            let reversed: String = s.chars().rev().collect();
+            assert_eq!(
+                BF::from_str_be(s).unwrap(),
+                BF::from_str(&reversed).unwrap()
+            );
+        }
+
+        assert_eq!(BF::from_str_be("011"), Err(BFError::NotPowTwo(3)));
+        assert_eq!(BF::from_str_be("1"), Err(BFError::NotPowTwo(1)));
+        assert_eq!(
+            BF::from_str_be("012a"),
+            Err(BFError::InvalidString(String::from("012a")))
+        );
+    }
+
+    #[test]
+    fn support_matrix_works() {
+        let bf = BF::from_str("01101000").unwrap();
+        let bm = bf.support_matrix().unwrap();
+        assert_eq!(bm.to_string(), "100\n010\n001");
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            let Ok(bm) = bf.support_matrix() else {
+                assert_eq!(bf.weight(), 0);
+                continue;
+            };
+
+            assert_eq!(bm.rows(), bf.weight());
+            assert_eq!(bm.cols(), n);
+            for (row, args) in bf.support().enumerate() {
+                for col in 0..n {
+                    assert_eq!(bm.get(row, col) as usize, (args >> col) & 1);
+                }
+            }
+        }
+
+        assert_eq!(
+            BF::zero(3).unwrap().support_matrix().unwrap_err(),
+            BFError::ZeroFunction
+        );
+    }
+
+    #[test]
+    fn support_weight_enumerator_works() {
+        // Function equals 1 exactly on arguments of weight 2.
+        let bf = BF::from_str("0001011001101000").unwrap();
+        assert_eq!(bf.support_weight_enumerator(), vec![0, 0, 6, 0, 0]);
+
+        assert_eq!(BF::zero(3).unwrap().support_weight_enumerator(), vec![0; 4]);
+        assert_eq!(
+            BF::one(4).unwrap().support_weight_enumerator(),
+            vec![1, 4, 6, 4, 1]
+        );
+
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            let enumerator = bf.support_weight_enumerator();
+            assert_eq!(enumerator.len(), n + 1);
+            assert_eq!(enumerator.iter().sum::<usize>(), bf.weight());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn walsh_adamar_parallel_works() {
+        for n in 1..=16 {
+            let bf = BF::random(n).unwrap();
+            assert_eq!(bf.walsh_adamar_parallel(), bf.walsh_adamar());
+        }
+    }
+
+    #[test]
+    fn autocor_at_works() {
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            for (a, &coef) in bf.autocor().iter().enumerate() {
+                assert_eq!(bf.autocor_at(a).unwrap(), coef);
+                assert_eq!(
+                    bf.autocor_at(a).unwrap(),
+                    pow2(n) as i64 - 2 * bf.derivative(a).unwrap().weight() as i64
+                );
+            }
+        }
+
+        // 2^31 doesn't fit into i32
+        assert_eq!(BF::one(31).unwrap().autocor_at(1), Ok(1 << 31));
+
+        assert_eq!(
+            BF::one(3).unwrap().autocor_at(8),
+            Err(BFError::ArgOutOfBounds {
+                given: 8,
+                bounds: 8
+            })
+        );
+    }
+
+    #[test]
+    fn nonlinearity_upper_bound_works() {
+        let bounds = (1..=8)
+            .map(|n| BF::zero(n).unwrap().nonlinearity_upper_bound())
+            .collect_vec();
+        assert_eq!(bounds, vec![0, 1, 2, 6, 13, 28, 58, 120]);
+
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            assert!(bf.nonlinearity() <= bf.nonlinearity_upper_bound());
+        }
+    }
+
+    #[test]
+    fn bentness_defect_works() {
+        let bent = BF::from_anf("x1&x2 + x3&x4", 4).unwrap();
+        assert_eq!(bent.bentness_defect(), 0);
+
+        let linear = BF::linear(4, 0b1011).unwrap();
+        assert_eq!(linear.bentness_defect(), 6);
+        assert_eq!(BF::zero(6).unwrap().bentness_defect(), 28);
+
+        // for odd arity bound is reached only by small functions, e.g. majority of 3 arguments
+        let majority = BF::from_str("00010111").unwrap();
+        assert_eq!(majority.bentness_defect(), 0);
+        let bf = BF::from_str("00000001").unwrap();
+        assert_eq!(bf.bentness_defect(), 1);
+    }
+
+    #[test]
+    fn monomials_of_degree_works() {
+        let bf = BF::from_anf("x1&x2&x3 + x1&x3 + x2&x3 + x1 + 1", 3).unwrap();
+        assert_eq!(
+            bf.monomials_of_degree(0).unwrap(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(bf.monomials_of_degree(1).unwrap(), vec![vec![1]]);
+        assert_eq!(
+            bf.monomials_of_degree(2).unwrap(),
+            vec![vec![3, 2], vec![3, 1]]
+        );
+        assert_eq!(bf.monomials_of_degree(3).unwrap(), vec![vec![3, 2, 1]]);
+
+        for n in 1..=8 {
+            let bf = BF::random(n).unwrap();
+            if bf.is_constant() != Some(0) {
+                assert!(!bf.monomials_of_degree(bf.deg()).unwrap().is_empty());
+            }
+
+            let total: usize = (0..=n)
+                .map(|deg| bf.monomials_of_degree(deg).unwrap().len())
+                .sum();
+            assert_eq!(total, bf.clone().mobius().weight());
+            assert_eq!(
+                bf.monomials_of_degree(n + 1),
+                Err(BFError::InvalidDeg(n + 1))
+            );
+        }
+    }
+
+    #[test]
+    fn threshold_works() {
+        assert_eq!(BF::threshold(3, 2).unwrap().to_string(), "00010111");
+        assert_eq!(BF::threshold(3, 3).unwrap().to_string(), "00000001");
+        assert_eq!(BF::threshold(3, 4).unwrap(), BF::zero(3).unwrap());
+
+        for n in 1..10 {
+            assert_eq!(BF::threshold(n, 0).unwrap(), BF::one(n).unwrap());
+            for t in 0..=n {
+                let bf = BF::threshold(n, t).unwrap();
+                assert_eq!(bf.distance_to_symmetric(), 0);
+                assert!(bf.support().all(|args| weight(args) >= t));
+            }
+        }
+
+        assert_eq!(BF::threshold(0, 1), Err(BFError::NoArgs));
+    }
+
+    #[test]
+    fn majority_works() {
+        assert_eq!(BF::majority(3).unwrap().to_string(), "00010111");
+        assert_eq!(BF::majority(1).unwrap().to_string(), "01");
+        assert_eq!(BF::majority(4).unwrap(), BF::threshold(4, 2).unwrap());
+        assert_eq!(BF::majority(5).unwrap().weight(), 16);
+    }
+
+    #[test]
+    fn best_restriction_works() {
+        // fixing x1 (bit 4) to 1 gives bent x2&x3 + x4&x5, to 0 gives x4&x5
+        let bf = BF::from_anf("x1&x2&x3 + x4&x5", 5).unwrap();
+        assert_eq!(bf.best_restriction(4), Ok((1, 6)));
+        assert_eq!(bf.restrict(4, 0).unwrap().nonlinearity(), 4);
+
+        // majority is symmetric under negation of all arguments, so both values give the same
+        let majority = BF::majority(5).unwrap();
+        for var in 0..5 {
+            let (value, nl) = majority.best_restriction(var).unwrap();
+            assert_eq!(value, 0);
+            assert_eq!(nl, majority.restrict(var, 1).unwrap().nonlinearity());
+        }
+
+        for n in 2..=9 {
+            let bf = BF::random(n).unwrap();
+            let (value, nl) = bf.best_restriction(0).unwrap();
+            assert_eq!(nl, bf.restrict(0, value).unwrap().nonlinearity());
+            assert!(nl >= bf.restrict(0, 1 - value).unwrap().nonlinearity());
+        }
+
+        assert_eq!(
+            bf.best_restriction(5),
+            Err(BFError::VarOutOfBounds {
+                given: 5,
+                bounds: 5
+            })
+        );
     }
 }
@@ -0,0 +1,109 @@
+use super::errors::{BFError, Result};
+use super::utils::{div_ws_ceil, mod_ws, pow2, WORD_BIT_SIZE};
+use super::{BF, MAX_ARGS};
+use crate::Value;
+use serde::{Deserialize, Serialize};
+
+/// Serialized form of `BF`.
+///
+/// Size of `Value` differs between builds, so it's stored in `word_bits`
+/// and checked on deserialization instead of silently misreading `values`.
+#[derive(Serialize, Deserialize)]
+pub(super) struct BFRepr {
+    args_amount: usize,
+    word_bits: usize,
+    values: Vec<Value>,
+}
+
+impl From<BF> for BFRepr {
+    fn from(bf: BF) -> Self {
+        BFRepr {
+            args_amount: bf.args_amount,
+            word_bits: WORD_BIT_SIZE,
+            values: bf.values,
+        }
+    }
+}
+
+impl TryFrom<BFRepr> for BF {
+    type Error = BFError;
+
+    fn try_from(repr: BFRepr) -> Result<Self> {
+        let BFRepr {
+            args_amount,
+            word_bits,
+            values,
+        } = repr;
+
+        if word_bits != WORD_BIT_SIZE {
+            return Err(BFError::WordSizeMismatch {
+                expected: WORD_BIT_SIZE,
+                given: word_bits,
+            });
+        }
+
+        if args_amount == 0 {
+            return Err(BFError::NoArgs);
+        }
+
+        if args_amount > MAX_ARGS {
+            return Err(BFError::TooManyArgs {
+                args_amount,
+                max: MAX_ARGS,
+            });
+        }
+
+        let expected = div_ws_ceil(pow2(args_amount));
+        if values.len() != expected {
+            return Err(BFError::InvalidLength {
+                given: values.len(),
+                expected,
+            });
+        }
+
+        let bits_in_last_factor = mod_ws(pow2(args_amount));
+        if bits_in_last_factor != 0 && values[0] >> bits_in_last_factor != 0 {
+            return Err(BFError::UnusedBitsSet);
+        }
+
+        Ok(BF {
+            values,
+            args_amount,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trip_works() {
+        for n in 1..=10 {
+            let bf = BF::random(n).unwrap();
+            let json = serde_json::to_string(&bf).unwrap();
+            assert_eq!(serde_json::from_str::<BF>(&json).unwrap(), bf);
+        }
+
+        let bf: BF = "0110".parse().unwrap();
+        let json = serde_json::to_string(&bf).unwrap();
+        assert_eq!(json, r#"{"args_amount":2,"word_bits":8,"values":[6]}"#);
+    }
+
+    #[test]
+    fn serde_validation_works() {
+        let parse = |json: &str| serde_json::from_str::<BF>(json).unwrap_err().to_string();
+
+        let err = parse(r#"{"args_amount":2,"word_bits":128,"values":[6]}"#);
+        assert!(err.contains("word size mismatch"));
+
+        let err = parse(r#"{"args_amount":4,"word_bits":8,"values":[6]}"#);
+        assert!(err.contains("length is invalid"));
+
+        let err = parse(r#"{"args_amount":2,"word_bits":8,"values":[22]}"#);
+        assert!(err.contains("bits outside of truth table"));
+
+        let err = parse(r#"{"args_amount":0,"word_bits":8,"values":[]}"#);
+        assert!(err.contains("args_amount is zero"));
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::unnecessary_cast)]
 
 use crate::Value;
+use std::ops::{Add, Sub};
 
 pub const WORD_SIZE: usize = std::mem::size_of::<Value>();
 pub const WORD_BIT_SIZE: usize = WORD_SIZE * 8;
@@ -30,6 +31,12 @@ impl Iterator for BinComb {
 
         let old = self.cur;
 
+        // the only combination of zero bits
+        if self.cur == 0 {
+            self.cur = 1 << self.n;
+            return Some(old);
+        }
+
         let lowbit = self.cur & !(self.cur - 1);
         let ones = self.cur & !(self.cur + lowbit);
         self.cur = self.cur + lowbit + ((ones / lowbit) >> 1);
@@ -124,6 +131,117 @@ pub fn weight(mut n: usize) -> usize {
     weight
 }
 
+/// Returns n-th element of binary reflected Gray code
+#[inline]
+pub fn gray(n: usize) -> usize {
+    n ^ (n >> 1)
+}
+
+/// Returns minimal amount of arguments (at least one) of a function,
+/// which truth table fits `table`: `2^args_amount` should be not less than
+/// position of the highest set bit plus one.
+pub fn min_args_for_table(table: u128) -> usize {
+    let bits = (u128::BITS - table.leading_zeros()) as usize;
+
+    let mut args_amount = 1;
+    while pow2(args_amount) < bits {
+        args_amount += 1;
+    }
+    args_amount
+}
+
+/// Calculates fast Walsh-Hadamard transform of `data` inplace.
+///
+/// # Panics
+/// Panics if length of `data` is not a power of two.
+pub fn fast_hadamard_transform<T>(data: &mut [T])
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    assert!(is_pow2(data.len()), "data length should be power of two");
+
+    let mut cs = 1;
+    while cs < data.len() {
+        for j in (0..data.len()).step_by(2 * cs) {
+            for k in j..j + cs {
+                let a = data[k];
+                let b = data[k + cs];
+                data[k] = a + b;
+                data[k + cs] = a - b;
+            }
+        }
+        cs <<= 1;
+    }
+}
+
+/// Calculates fast Walsh-Hadamard transform of `data` inplace,
+/// processing blocks of every butterfly stage in parallel.
+///
+/// # Panics
+/// Panics if length of `data` is not a power of two.
+#[cfg(feature = "rayon")]
+pub fn fast_hadamard_transform_parallel(data: &mut [i64]) {
+    use rayon::prelude::*;
+
+    assert!(is_pow2(data.len()), "data length should be power of two");
+
+    let mut cs = 1;
+    while cs < data.len() {
+        data.par_chunks_mut(2 * cs).for_each(|block| {
+            let (lo, hi) = block.split_at_mut(cs);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let (x, y) = (*a, *b);
+                *a = x + y;
+                *b = x - y;
+            }
+        });
+        cs <<= 1;
+    }
+}
+
+/// Checks that `perm` is a permutation of `0..n`
+pub fn is_permutation(perm: &[usize], n: usize) -> bool {
+    if perm.len() != n {
+        return false;
+    }
+
+    let mut seen = vec![false; n];
+    for &p in perm {
+        if p >= n || seen[p] {
+            return false;
+        }
+        seen[p] = true;
+    }
+
+    true
+}
+
+/// Calculates dimension of affine span of `points` viewed as binary vectors.
+/// Returns `0` for an empty set.
+pub fn affine_span_dimension(points: &[usize]) -> usize {
+    let Some((&origin, rest)) = points.split_first() else {
+        return 0;
+    };
+
+    // basis[i] has the highest set bit `i`
+    let mut basis = [0usize; usize::BITS as usize];
+    let mut dimension = 0;
+    for &point in rest {
+        let mut v = point ^ origin;
+        while v != 0 {
+            let high = (usize::BITS - 1 - v.leading_zeros()) as usize;
+            if basis[high] == 0 {
+                basis[high] = v;
+                dimension += 1;
+                break;
+            }
+            v ^= basis[high];
+        }
+    }
+
+    dimension
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,5 +305,96 @@ mod tests {
         for comb in BinComb::new(5, 2) {
             println!("{comb:05b}");
         }
+
+        assert_eq!(BinComb::new(5, 0).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn affine_span_dimension_works() {
+        assert_eq!(affine_span_dimension(&[]), 0);
+        assert_eq!(affine_span_dimension(&[5]), 0);
+        assert_eq!(affine_span_dimension(&[1, 3, 5, 7]), 2);
+        assert_eq!(affine_span_dimension(&[1, 2, 4]), 2);
+        assert_eq!(affine_span_dimension(&[0, 1, 2, 4]), 3);
+        assert_eq!(affine_span_dimension(&[3, 5, 6]), 2);
+        assert_eq!(affine_span_dimension(&(0..16).collect::<Vec<_>>()), 4);
+    }
+
+    #[test]
+    fn is_permutation_works() {
+        assert!(is_permutation(&[0, 1, 2], 3));
+        assert!(is_permutation(&[2, 0, 1], 3));
+        assert!(is_permutation(&[], 0));
+
+        assert!(!is_permutation(&[0, 1], 3));
+        assert!(!is_permutation(&[0, 1, 1], 3));
+        assert!(!is_permutation(&[0, 1, 3], 3));
+    }
+
+    #[test]
+    fn fast_hadamard_transform_works() {
+        let mut data = vec![1, 0, 0, 0];
+        fast_hadamard_transform(&mut data);
+        assert_eq!(data, vec![1, 1, 1, 1]);
+
+        let mut data = vec![1, -1, -1, 1];
+        fast_hadamard_transform(&mut data);
+        assert_eq!(data, vec![0, 0, 0, 4]);
+
+        let original: Vec<i64> = vec![3, -7, 0, 12, 5, 5, -1, 8];
+        let mut data = original.clone();
+        fast_hadamard_transform(&mut data);
+        fast_hadamard_transform(&mut data);
+        let scaled: Vec<i64> = original.iter().map(|v| v * 8).collect();
+        assert_eq!(data, scaled);
+
+        // spectrum of constant one of 32 arguments reaches -2^32
+        let mut data = vec![-(1i64 << 29); 8];
+        fast_hadamard_transform(&mut data);
+        assert_eq!(data, vec![-(1i64 << 32), 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut data = vec![1i64 << 31, -(1i64 << 31), 1i64 << 31, -(1i64 << 31)];
+        fast_hadamard_transform(&mut data);
+        assert_eq!(data, vec![0, 1i64 << 33, 0, 0]);
+
+        // squared coefficients need wider type
+        let mut data = vec![1i128 << 64, 0, 0, 0];
+        fast_hadamard_transform(&mut data);
+        assert_eq!(data, vec![1i128 << 64; 4]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn fast_hadamard_transform_parallel_works() {
+        for n in 0..=12 {
+            let original: Vec<i64> = (0..pow2(n) as i64).map(|v| (v * 7) % 13 - 6).collect();
+            let mut sequential = original.clone();
+            let mut parallel = original;
+            fast_hadamard_transform(&mut sequential);
+            fast_hadamard_transform_parallel(&mut parallel);
+            assert_eq!(parallel, sequential);
+        }
+    }
+
+    #[test]
+    fn gray_works() {
+        let codes: Vec<usize> = (0..8).map(gray).collect();
+        assert_eq!(codes, vec![0, 1, 3, 2, 6, 7, 5, 4]);
+
+        for i in 1..1024 {
+            assert_eq!(weight(gray(i) ^ gray(i - 1)), 1);
+        }
+    }
+
+    #[test]
+    fn min_args_for_table_works() {
+        assert_eq!(min_args_for_table(0b0110), 2);
+        assert_eq!(min_args_for_table(0), 1);
+        assert_eq!(min_args_for_table(0b11), 1);
+        assert_eq!(min_args_for_table(0b100), 2);
+        assert_eq!(min_args_for_table(0b1_0000), 3);
+        assert_eq!(min_args_for_table(u64::MAX as u128), 6);
+        assert_eq!(min_args_for_table(1 << 64), 7);
+        assert_eq!(min_args_for_table(u128::MAX), 7);
     }
 }
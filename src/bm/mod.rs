@@ -1,13 +1,14 @@
 pub mod errors;
 
 use crate::bf::{
-    utils::{comb, div_ws, div_ws_ceil, mod_ws, pow2, BinComb},
+    utils::{comb, div_ws, div_ws_ceil, mod_ws, pow2},
     BF,
 };
 use errors::{BMError, Result};
 use rand::{distributions::Uniform, Rng};
 use std::{
     fmt::{self, Debug},
+    io::{self, Read, Write},
     str::FromStr,
 };
 
@@ -52,24 +53,34 @@ impl BM {
         Ok(BM { mat, rows, cols })
     }
 
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
     pub fn rank(&self) -> usize {
+        self.rank_with_pivots().0
+    }
+
+    // Returns rank and columns of pivots found during gaussian elimination
+    pub fn rank_with_pivots(&self) -> (usize, Vec<usize>) {
         let mut bm = self.clone();
         bm.gaussian_elimination();
-        for row in (0..bm.rows).rev() {
-            for col in 0..bm.cols {
-                if bm.get(row, col) != 0 {
-                    return row + 1;
-                }
-            }
-        }
 
-        0
+        let pivots: Vec<usize> = (0..bm.rows)
+            .map_while(|row| (0..bm.cols).find(|&col| bm.get(row, col) != 0))
+            .collect();
+
+        (pivots.len(), pivots)
     }
 
     pub fn get(&self, row: usize, col: usize) -> u8 {
         let factor = div_ws(row * self.cols + col);
         let bit = mod_ws(row * self.cols + col);
-        ((self.mat[factor] >> bit) & 1) as u8
+        u8::from((self.mat[factor] >> bit) & 1 == 1)
     }
 
     pub fn set(&mut self, row: usize, col: usize) {
@@ -89,6 +100,25 @@ impl BM {
         self.mat[factor] &= mask;
     }
 
+    pub fn row(&self, row: usize) -> Vec<u8> {
+        (0..self.cols).map(|col| self.get(row, col)).collect()
+    }
+
+    // Checks if matrices consist of the same rows, maybe in different order
+    pub fn row_permutation_equal(&self, other: &BM) -> bool {
+        if self.rows != other.rows || self.cols != other.cols {
+            return false;
+        }
+
+        let sorted_rows = |bm: &BM| -> Vec<Vec<u8>> {
+            let mut rows: Vec<Vec<u8>> = (0..bm.rows).map(|row| bm.row(row)).collect();
+            rows.sort();
+            rows
+        };
+
+        sorted_rows(self) == sorted_rows(other)
+    }
+
     // Builds a matrix of a form:
     // for x1...xn where bf.eval = 1:
     // 1 x1 ... xn x1x2 ... xn-1 xn ...
@@ -110,15 +140,15 @@ impl BM {
             bm.set(i, 0);
         }
 
-        for (row, args) in (0..pow2(bf.args_amount))
-            .filter(|&args| bf.eval(args) == 1)
-            .enumerate()
-        {
+        for (row, args) in bf.support().enumerate() {
             // args -- values of x's
             // comb < args
             let mut col = 1;
             for d in 1..=deg {
-                for comb in BinComb::new(bf.args_amount, d) {
+                for comb in bf
+                    .monomial_masks(d)
+                    .expect("d is not greater than args_amount")
+                {
                     if comb & args == comb {
                         bm.set(row, col);
                     }
@@ -130,6 +160,125 @@ impl BM {
         Ok(bm)
     }
 
+    // Builds a matrix, which rows are truth tables of given functions
+    pub fn from_rows(bfs: &[BF]) -> Result<Self> {
+        let Some(first) = bfs.first() else {
+            return Err(BMError::ZeroDim(0, 0));
+        };
+
+        if bfs.iter().any(|bf| bf.args_amount != first.args_amount) {
+            return Err(BMError::InconsistentDim);
+        }
+
+        let cols = pow2(first.args_amount);
+        let mut bm = BM::zero(bfs.len(), cols)?;
+        for (row, bf) in bfs.iter().enumerate() {
+            for col in 0..cols {
+                if bf.eval(col) == 1 {
+                    bm.set(row, col);
+                }
+            }
+        }
+
+        Ok(bm)
+    }
+
+    // Converts each row to a function of `args_amount` arguments
+    pub fn into_bfs(&self, args_amount: usize) -> Result<Vec<BF>> {
+        if args_amount == 0 || self.cols != pow2(args_amount) {
+            return Err(BMError::InconsistentDim);
+        }
+
+        let bfs = (0..self.rows)
+            .map(|row| {
+                let mut bf = BF::zero(args_amount).expect("args_amount is not zero");
+                for col in 0..self.cols {
+                    if self.get(row, col) == 1 {
+                        bf.set(col).expect("col is in bounds");
+                    }
+                }
+                bf
+            })
+            .collect();
+
+        Ok(bfs)
+    }
+
+    // Writes matrix in binary format:
+    // rows and cols as little endian u64, then bits packed into bytes row by row
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&(self.rows as u64).to_le_bytes())?;
+        writer.write_all(&(self.cols as u64).to_le_bytes())?;
+
+        let bits = self.rows * self.cols;
+        let mut bytes = vec![0u8; bits.div_ceil(8)];
+        for bit in (0..bits).filter(|&bit| (self.mat[div_ws(bit)] >> mod_ws(bit)) & 1 == 1) {
+            bytes[bit / 8] |= 1 << (bit % 8);
+        }
+
+        writer.write_all(&bytes)
+    }
+
+    // Reads matrix written by `write_to`
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let invalid_data = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header)?;
+        let rows = u64::from_le_bytes(header);
+        reader.read_exact(&mut header)?;
+        let cols = u64::from_le_bytes(header);
+
+        let (rows, cols) = match (usize::try_from(rows), usize::try_from(cols)) {
+            (Ok(rows), Ok(cols)) => (rows, cols),
+            _ => return Err(invalid_data("dimensions don't fit in usize")),
+        };
+        let bits = rows
+            .checked_mul(cols)
+            .ok_or_else(|| invalid_data("dimensions are too big"))?;
+
+        // header is untrusted, so payload is read before anything is allocated for it
+        let len = bits.div_ceil(8);
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "data is shorter than dimensions require",
+            ));
+        }
+
+        let mut bm = BM::zero(rows, cols).map_err(|e| invalid_data(&e.to_string()))?;
+
+        if bits % 8 != 0 && bytes[bytes.len() - 1] >> (bits % 8) != 0 {
+            return Err(invalid_data("bits outside of matrix are set"));
+        }
+
+        for bit in (0..bits).filter(|bit| (bytes[bit / 8] >> (bit % 8)) & 1 == 1) {
+            bm.mat[div_ws(bit)] |= 1 << mod_ws(bit);
+        }
+
+        Ok(bm)
+    }
+
+    // Checks if pivot of each row is strictly to the right of pivot of the row above
+    // and zero rows are at the bottom
+    pub fn is_row_echelon(&self) -> bool {
+        let mut prev_pivot: Option<usize> = None;
+        let mut zero_row_seen = false;
+
+        for row in 0..self.rows {
+            match (0..self.cols).find(|&col| self.get(row, col) != 0) {
+                None => zero_row_seen = true,
+                Some(_) if zero_row_seen => return false,
+                Some(pivot) if prev_pivot.is_some_and(|prev| prev >= pivot) => return false,
+                Some(pivot) => prev_pivot = Some(pivot),
+            }
+        }
+
+        true
+    }
+
     pub fn gaussian_elimination(&mut self) {
         let mut cur_row = 0;
         let mut cur_col = 0;
@@ -260,6 +409,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::to_string_in_format_args)]
     fn gauss_works() {
         let s = "0110\n1101\n1111\n1111";
         let mut bm = BM::from_str(s).unwrap();
@@ -284,5 +434,125 @@ mod tests {
         let deg = 2;
         let bm = BM::monomial(&bf, deg).unwrap();
         println!("{bm}");
+
+        // rows follow support, degree 1 columns are arguments bits
+        assert_eq!(bm.rows(), bf.weight());
+        assert_eq!(bm.cols(), 7);
+        for (row, args) in bf.support().enumerate() {
+            assert_eq!(bm.get(row, 0), 1);
+            for (col, mask) in bf.monomial_masks(1).unwrap().enumerate() {
+                assert_eq!(bm.get(row, col + 1) == 1, args & mask == mask);
+            }
+        }
+    }
+
+    #[test]
+    fn from_rows_works() {
+        let bfs: Vec<BF> = (0..5).map(|_| BF::random(3).unwrap()).collect();
+        let bm = BM::from_rows(&bfs).unwrap();
+        assert_eq!(bm.rows(), 5);
+        assert_eq!(bm.cols(), 8);
+        assert_eq!(bm.into_bfs(3).unwrap(), bfs);
+
+        let bm = BM::from_rows(&[BF::from_str("0110").unwrap()]).unwrap();
+        assert_eq!(bm.to_string(), "0110");
+
+        assert!(matches!(BM::from_rows(&[]), Err(BMError::ZeroDim(0, 0))));
+        let bfs = [BF::zero(2).unwrap(), BF::zero(3).unwrap()];
+        assert!(matches!(BM::from_rows(&bfs), Err(BMError::InconsistentDim)));
+        assert!(matches!(bm.into_bfs(3), Err(BMError::InconsistentDim)));
+    }
+
+    #[test]
+    fn rank_with_pivots_works() {
+        let s = "0110\n1101\n1111\n1111";
+        let bm = BM::from_str(s).unwrap();
+        assert_eq!(bm.rank_with_pivots(), (3, vec![0, 1, 2]));
+
+        let s = "0011\n0110\n0101";
+        let bm = BM::from_str(s).unwrap();
+        assert_eq!(bm.rank_with_pivots(), (2, vec![1, 2]));
+
+        let s = "000\n000";
+        let bm = BM::from_str(s).unwrap();
+        assert_eq!(bm.rank_with_pivots(), (0, vec![]));
+    }
+
+    #[test]
+    fn row_permutation_equal_works() {
+        let bm = BM::from_str("0110\n1101\n1111\n0110").unwrap();
+        let shuffled = BM::from_str("1111\n0110\n0110\n1101").unwrap();
+        assert!(bm.row_permutation_equal(&shuffled));
+        assert!(shuffled.row_permutation_equal(&bm));
+
+        let other = BM::from_str("1111\n0110\n1101\n1101").unwrap();
+        assert!(!bm.row_permutation_equal(&other));
+
+        let other = BM::from_str("0110\n1101\n1111").unwrap();
+        assert!(!bm.row_permutation_equal(&other));
+
+        assert_eq!(bm.row(1), vec![1, 1, 0, 1]);
+    }
+
+    #[test]
+    fn binary_format_works() {
+        for (rows, cols) in [(1, 1), (3, 5), (7, 16), (20, 33)] {
+            let bm = BM::random(rows, cols).unwrap();
+
+            let mut buf = Vec::new();
+            bm.write_to(&mut buf).unwrap();
+            assert_eq!(buf.len(), 16 + (rows * cols).div_ceil(8));
+
+            let read = BM::read_from(&mut io::Cursor::new(buf)).unwrap();
+            assert_eq!(read.to_string(), bm.to_string());
+        }
+
+        let bm = BM::from_str("101\n011").unwrap();
+        let mut buf = Vec::new();
+        bm.write_to(&mut buf).unwrap();
+        assert_eq!(buf[16..], [0b110101]);
+
+        // data is shorter than dimensions require
+        let mut short = buf.clone();
+        short[0] = 3;
+        let err = BM::read_from(&mut io::Cursor::new(short)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        // bits outside of matrix are set
+        let mut dirty = buf.clone();
+        dirty[16] |= 0b1000000;
+        let err = BM::read_from(&mut io::Cursor::new(dirty)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // huge dimensions with no data fail without allocating
+        let mut huge = buf.clone();
+        huge[..8].copy_from_slice(&(1u64 << 30).to_le_bytes());
+        huge[8..16].copy_from_slice(&(1u64 << 30).to_le_bytes());
+        let err = BM::read_from(&mut io::Cursor::new(huge)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut zero = buf;
+        zero[8] = 0;
+        let err = BM::read_from(&mut io::Cursor::new(zero)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn is_row_echelon_works() {
+        assert!(BM::from_str("1101\n0110\n0001\n0000")
+            .unwrap()
+            .is_row_echelon());
+        assert!(BM::from_str("0110\n0001").unwrap().is_row_echelon());
+        assert!(BM::from_str("000\n000").unwrap().is_row_echelon());
+
+        assert!(!BM::from_str("0110\n1101").unwrap().is_row_echelon());
+        assert!(!BM::from_str("0110\n0101").unwrap().is_row_echelon());
+        assert!(!BM::from_str("1101\n0000\n0010").unwrap().is_row_echelon());
+
+        for _ in 0..20 {
+            let mut bm = BM::random(6, 9).unwrap();
+            bm.gaussian_elimination();
+            assert!(bm.is_row_echelon());
+        }
     }
 }
@@ -1,5 +1,6 @@
 pub mod bf;
 pub mod bm;
+pub mod sbox;
 
 #[cfg(test)]
 type Value = u8;
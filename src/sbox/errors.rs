@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, SBoxError>;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SBoxError {
+    #[error("table length should be power of two greater than one (got: `{0}`)")]
+    NotPowTwo(usize),
+    #[error("amount of output bits is zero (should be greater)")]
+    NoOutputs,
+    #[error("amount of output bits ({outputs}) is too big (max: {max})")]
+    TooManyOutputs { outputs: usize, max: usize },
+    #[error("given output ({given}) is out of bounds ({bounds})")]
+    OutputOutOfBounds { given: usize, bounds: usize },
+}
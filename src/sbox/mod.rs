@@ -0,0 +1,177 @@
+pub mod errors;
+
+use crate::bf::{
+    utils::{is_pow2, log2, pow2, weight},
+    BF,
+};
+use errors::{Result, SBoxError};
+
+/// Maximal amount of output bits an S-box can be created with.
+///
+/// Degree helpers go over all `2^outputs - 1` nonzero components, so wider
+/// S-boxes are impractical.
+pub const MAX_OUTPUTS: usize = 16;
+
+/// Vectorial boolean function (substitution box) from `n` to `m` bits,
+/// given by its lookup table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SBox {
+    table: Vec<usize>,
+    inputs: usize,
+    outputs: usize,
+}
+
+impl SBox {
+    /// Builds S-box with `outputs` output bits from lookup table.
+    ///
+    /// # Errors
+    /// Returns `SBoxError::NotPowTwo` if table length is not a power of two greater than one,
+    /// `SBoxError::NoOutputs` if `outputs == 0`,
+    /// `SBoxError::TooManyOutputs` if `outputs > MAX_OUTPUTS`,
+    /// `SBoxError::OutputOutOfBounds` if table contains value `>= 2^outputs`.
+    pub fn new(table: Vec<usize>, outputs: usize) -> Result<Self> {
+        if table.len() < 2 || !is_pow2(table.len()) {
+            return Err(SBoxError::NotPowTwo(table.len()));
+        }
+
+        if outputs == 0 {
+            return Err(SBoxError::NoOutputs);
+        }
+
+        if outputs > MAX_OUTPUTS {
+            return Err(SBoxError::TooManyOutputs {
+                outputs,
+                max: MAX_OUTPUTS,
+            });
+        }
+
+        if let Some(&given) = table.iter().find(|&&value| value >= pow2(outputs)) {
+            return Err(SBoxError::OutputOutOfBounds {
+                given,
+                bounds: pow2(outputs),
+            });
+        }
+
+        Ok(SBox {
+            inputs: log2(table.len()),
+            table,
+            outputs,
+        })
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.outputs
+    }
+
+    pub fn eval(&self, args: usize) -> usize {
+        self.table[args]
+    }
+
+    /// Returns component function `<b, S(x)>`.
+    ///
+    /// # Panics
+    /// Panics if `b >= 2^outputs`.
+    pub fn component(&self, b: usize) -> BF {
+        assert!(b < pow2(self.outputs), "component mask is out of bounds");
+
+        let mut bf = BF::zero(self.inputs).expect("inputs is not zero");
+        for (args, &value) in self.table.iter().enumerate() {
+            if weight(b & value) & 1 == 1 {
+                bf.set(args).expect("args is in bounds");
+            }
+        }
+
+        bf
+    }
+
+    /// Returns algebraic degrees of all nonzero component functions `<b, S(x)>`,
+    /// where `b` goes from `1` to `2^outputs - 1`.
+    pub fn component_degrees(&self) -> Vec<usize> {
+        (1..pow2(self.outputs))
+            .map(|b| self.component(b).deg())
+            .collect()
+    }
+
+    /// Returns algebraic degree of S-box: maximal degree of its components.
+    pub fn max_degree(&self) -> usize {
+        self.component_degrees().into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns minimum degree of S-box: minimal degree of its nonzero components.
+    pub fn min_degree(&self) -> usize {
+        self.component_degrees().into_iter().min().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // PRESENT cipher S-box
+    const PRESENT: [usize; 16] = [
+        0xC, 0x5, 0x6, 0xB, 0x9, 0x0, 0xA, 0xD, 0x3, 0xE, 0xF, 0x8, 0x4, 0x7, 0x1, 0x2,
+    ];
+
+    #[test]
+    fn new_works() {
+        let sbox = SBox::new(PRESENT.to_vec(), 4).unwrap();
+        assert_eq!(sbox.inputs(), 4);
+        assert_eq!(sbox.outputs(), 4);
+        assert_eq!(sbox.eval(1), 5);
+
+        assert_eq!(SBox::new(vec![0; 3], 1), Err(SBoxError::NotPowTwo(3)));
+        assert_eq!(SBox::new(vec![0], 1), Err(SBoxError::NotPowTwo(1)));
+        assert_eq!(SBox::new(vec![0, 1], 0), Err(SBoxError::NoOutputs));
+        assert_eq!(
+            SBox::new(vec![0, 1], 64),
+            Err(SBoxError::TooManyOutputs {
+                outputs: 64,
+                max: MAX_OUTPUTS
+            })
+        );
+        assert_eq!(
+            SBox::new(vec![0, 1], MAX_OUTPUTS + 1),
+            Err(SBoxError::TooManyOutputs {
+                outputs: MAX_OUTPUTS + 1,
+                max: MAX_OUTPUTS
+            })
+        );
+        assert!(SBox::new(vec![0, pow2(MAX_OUTPUTS) - 1], MAX_OUTPUTS).is_ok());
+        assert_eq!(
+            SBox::new(vec![0, 2], 1),
+            Err(SBoxError::OutputOutOfBounds {
+                given: 2,
+                bounds: 2
+            })
+        );
+    }
+
+    #[test]
+    fn component_degrees_works() {
+        // every nonzero component of PRESENT S-box has degree 2 or 3
+        let sbox = SBox::new(PRESENT.to_vec(), 4).unwrap();
+        let degrees = sbox.component_degrees();
+        assert_eq!(degrees.len(), 15);
+        assert!(degrees.iter().all(|&deg| deg == 2 || deg == 3));
+        assert_eq!(sbox.max_degree(), 3);
+        assert_eq!(sbox.min_degree(), 2);
+
+        // identity: every component is linear
+        let sbox = SBox::new((0..8).collect(), 3).unwrap();
+        assert_eq!(sbox.component_degrees(), vec![1; 7]);
+        assert_eq!(sbox.max_degree(), 1);
+        assert_eq!(sbox.min_degree(), 1);
+
+        // x -> x1&x2&x3, x1
+        let table = (0..8)
+            .map(|x| usize::from(x == 7) | ((x >> 2) << 1))
+            .collect();
+        let sbox = SBox::new(table, 2).unwrap();
+        assert_eq!(sbox.component_degrees(), vec![3, 1, 3]);
+        assert_eq!(sbox.component(2).to_string(), "00001111");
+    }
+}